@@ -0,0 +1,145 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// How many copies of a card are allowed in a deck.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LimitStatus {
+    Forbidden,
+    Limited,
+    SemiLimited,
+    Unlimited,
+}
+
+impl LimitStatus {
+    /// Returns the status that allows `copies` copies of a card, or `None` for more than 3 copies.
+    ///
+    /// # Arguments
+    ///
+    /// * `copies` – Number of allowed copies, as used in banlist files.
+    pub fn from_copies(copies: u8) -> Option<Self> {
+        match copies {
+            0 => Some(LimitStatus::Forbidden),
+            1 => Some(LimitStatus::Limited),
+            2 => Some(LimitStatus::SemiLimited),
+            3 => Some(LimitStatus::Unlimited),
+            _ => None,
+        }
+    }
+
+    /// Number of copies of a card with this status a deck may contain.
+    pub fn max_copies(&self) -> u8 {
+        match self {
+            LimitStatus::Forbidden => 0,
+            LimitStatus::Limited => 1,
+            LimitStatus::SemiLimited => 2,
+            LimitStatus::Unlimited => 3,
+        }
+    }
+}
+
+impl fmt::Display for LimitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitStatus::Forbidden => write!(f, "Forbidden"),
+            LimitStatus::Limited => write!(f, "Limited"),
+            LimitStatus::SemiLimited => write!(f, "Semi-Limited"),
+            LimitStatus::Unlimited => write!(f, "Unlimited"),
+        }
+    }
+}
+
+/// Limit statuses of the cards on one banlist. Cards that are not on the banlist are unlimited,
+/// unless the banlist is a whitelist.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct Banlist {
+    cards: HashMap<u32, LimitStatus>,
+    /// Whether or not cards that are not on the banlist are forbidden.
+    whitelist: bool,
+}
+
+impl Banlist {
+    /// Constructs an empty [`Banlist`] on which every card is unlimited.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the limit status of a card.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    pub fn status_of(&self, id: u32) -> LimitStatus {
+        match self.cards.get(&id) {
+            Some(status) => *status,
+            None if self.whitelist => LimitStatus::Forbidden,
+            None => LimitStatus::Unlimited,
+        }
+    }
+
+    /// Whether or not only the cards on the banlist are allowed.
+    pub fn is_whitelist(&self) -> bool {
+        self.whitelist
+    }
+
+    /// Sets whether or not only the cards on the banlist are allowed. Unlimited cards have to be
+    /// listed explicitly with [`set_status`][`Banlist::set_status`] in this case.
+    ///
+    /// # Arguments
+    ///
+    /// * `whitelist` – Whether or not cards that are not on the banlist are forbidden.
+    pub fn set_whitelist(&mut self, whitelist: bool) {
+        self.whitelist = whitelist;
+    }
+
+    /// Whether or not a card is forbidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    pub fn is_forbidden(&self, id: u32) -> bool {
+        self.status_of(id) == LimitStatus::Forbidden
+    }
+
+    /// Sets the limit status of a card.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    /// * `status` – New limit status of the card.
+    pub fn set_status(&mut self, id: u32, status: LimitStatus) {
+        self.cards.insert(id, status);
+    }
+
+    /// Iterates over all cards that are listed on the banlist and their limit status.
+    pub fn iter(&self) -> impl Iterator<Item = (&u32, &LimitStatus)> {
+        self.cards.iter()
+    }
+}
+
+impl FromIterator<(u32, LimitStatus)> for Banlist {
+    fn from_iter<I: IntoIterator<Item = (u32, LimitStatus)>>(iter: I) -> Self {
+        Self {
+            cards: iter.into_iter().collect(),
+            whitelist: false,
+        }
+    }
+}
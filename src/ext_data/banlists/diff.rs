@@ -0,0 +1,147 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+
+use crate::ext_data::cardinfo::CardinfoMetaType;
+
+use super::{banlist_at, Banlist, BanlistsMetaType, LimitStatus};
+
+/// A card whose limit differs between two banlists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BanlistChange {
+    pub id: u32,
+    /// Name of the card, or `None` if the card is not contained in the card data.
+    pub name: Option<String>,
+    /// Status in the older banlist.
+    pub previous: LimitStatus,
+    /// Status in the newer banlist.
+    pub new: LimitStatus,
+}
+
+/// Differences between the banlists in effect on two dates, as returned by [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BanlistDiff {
+    /// Date the older banlist came into effect, or `None` if there was no banlist yet.
+    pub from: Option<NaiveDate>,
+    /// Date the newer banlist came into effect, or `None` if there was no banlist yet.
+    pub to: Option<NaiveDate>,
+    /// Whether or not the older banlist is a whitelist, see [`Banlist::is_whitelist`].
+    pub from_whitelist: bool,
+    /// Whether or not the newer banlist is a whitelist, see [`Banlist::is_whitelist`].
+    pub to_whitelist: bool,
+    /// Changed cards, sorted by their new status and name.
+    pub changes: Vec<BanlistChange>,
+}
+
+impl BanlistDiff {
+    /// Cards that are forbidden in the newer banlist.
+    pub fn forbidden(&self) -> Vec<&BanlistChange> {
+        self.changes_to(LimitStatus::Forbidden)
+    }
+
+    /// Cards that are limited in the newer banlist.
+    pub fn limited(&self) -> Vec<&BanlistChange> {
+        self.changes_to(LimitStatus::Limited)
+    }
+
+    /// Cards that are semi-limited in the newer banlist.
+    pub fn semi_limited(&self) -> Vec<&BanlistChange> {
+        self.changes_to(LimitStatus::SemiLimited)
+    }
+
+    /// Cards that are unlimited in the newer banlist.
+    pub fn unlimited(&self) -> Vec<&BanlistChange> {
+        self.changes_to(LimitStatus::Unlimited)
+    }
+
+    fn changes_to(&self, status: LimitStatus) -> Vec<&BanlistChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.new == status)
+            .collect()
+    }
+}
+
+/// Compares the banlists that were in effect on two dates.
+///
+/// Cards that are not listed on a whitelist are forbidden, so if either banlist is a whitelist,
+/// every card in `cardinfo` is compared.
+///
+/// # Arguments
+///
+/// * `banlists` – Processed banlists, usually [`Cache::banlists`][`crate::utils::Cache::banlists`].
+/// * `cardinfo` – Card data used to look up card names.
+/// * `from` – Date of the older banlist.
+/// * `to` – Date of the newer banlist.
+pub fn diff(
+    banlists: &BanlistsMetaType,
+    cardinfo: &CardinfoMetaType,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> BanlistDiff {
+    let empty = Banlist::new();
+    let (from_date, from_list) =
+        banlist_at(banlists, from).map_or((None, &empty), |(date, banlist)| (Some(*date), banlist));
+    let (to_date, to_list) =
+        banlist_at(banlists, to).map_or((None, &empty), |(date, banlist)| (Some(*date), banlist));
+
+    let mut ids: Vec<u32> = from_list
+        .iter()
+        .chain(to_list.iter())
+        .map(|(id, _)| *id)
+        .collect();
+    if from_list.is_whitelist() || to_list.is_whitelist() {
+        ids.extend(cardinfo.keys());
+    }
+
+    let mut changes: Vec<BanlistChange> = Vec::new();
+    let mut compared: HashSet<u32> = HashSet::new();
+
+    for id in ids.iter() {
+        let previous = from_list.status_of(*id);
+        let new = to_list.status_of(*id);
+
+        if !compared.insert(*id) || previous == new {
+            continue;
+        }
+
+        changes.push(BanlistChange {
+            id: *id,
+            name: cardinfo.get(id).map(|card| card.name.clone()),
+            previous: previous,
+            new: new,
+        });
+    }
+
+    changes.sort_by(|a, b| {
+        a.new
+            .cmp(&b.new)
+            .then_with(|| a.name.cmp(&b.name))
+            .then(a.id.cmp(&b.id))
+    });
+
+    return BanlistDiff {
+        from: from_date,
+        to: to_date,
+        from_whitelist: from_list.is_whitelist(),
+        to_whitelist: to_list.is_whitelist(),
+        changes: changes,
+    };
+}
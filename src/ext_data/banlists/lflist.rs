@@ -0,0 +1,63 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::ext_data::cardinfo::CardinfoMetaType;
+
+use super::Banlist;
+
+impl Banlist {
+    /// Returns the banlist as a section of an EDOPro `lflist.conf` file. Multiple sections can be
+    /// joined into one file.
+    ///
+    /// Cards are ordered by their limit status and name. Each card is followed by its name as a
+    /// comment, if it is contained in `cardinfo`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the banlist as it is shown in EDOPro.
+    /// * `cardinfo` – Card data used to look up card names.
+    pub fn to_lflist(&self, name: &str, cardinfo: &CardinfoMetaType) -> String {
+        let mut lflist = format!("!{}\n", name);
+
+        if self.is_whitelist() {
+            lflist.push_str("$whitelist\n");
+        }
+
+        let mut cards: Vec<(&u32, Option<&String>)> = self
+            .iter()
+            .map(|(id, _)| (id, cardinfo.get(id).map(|card| &card.name)))
+            .collect();
+        cards.sort_by(|a, b| {
+            self.status_of(*a.0)
+                .cmp(&self.status_of(*b.0))
+                .then_with(|| a.1.cmp(&b.1))
+                .then(a.0.cmp(b.0))
+        });
+
+        for (id, name) in cards {
+            lflist.push_str(&format!("{} {}", id, self.status_of(*id).max_copies()));
+
+            if let Some(name) = name {
+                lflist.push_str(&format!(" --{}", name));
+            }
+
+            lflist.push('\n');
+        }
+
+        return lflist;
+    }
+}
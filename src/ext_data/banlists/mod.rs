@@ -15,79 +15,237 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::collections::HashMap;
-
-use regex::Regex;
-
-/// Banlist element (card with limit number) type.
-pub type BanlistElementType = HashMap<u32, u8>;
+/// Limit statuses of the cards on a banlist.
+mod banlist;
+/// Comparison of two banlists.
+mod diff;
+/// Export to the EDOPro banlist format.
+mod lflist;
+
+pub use banlist::{Banlist, LimitStatus};
+pub use diff::{diff, BanlistChange, BanlistDiff};
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::NaiveDate;
+use pest::error::{Error, LineColLocation};
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+use serde::{Deserialize, Serialize};
+
+/// Banlists of one [`BanlistSource`], sorted by the date they came into effect. Banlists without
+/// a date, like the lists of most formats, are in effect from [`NaiveDate::MIN`].
+pub type BanlistsMetaType = BTreeMap<NaiveDate, Banlist>;
 /// Type contained in a processed banlist binary file.
-pub type BanlistsMetaType = HashMap<String, BanlistElementType>;
+pub type BanlistSourcesMetaType = BTreeMap<BanlistSource, BanlistsMetaType>;
 
-/// External [URL](https://ygo.anihelp.co.uk/public/config3/TCGCombiList.conf) to the banlist data.
+/// External [URL](https://ygo.anihelp.co.uk/public/config3/) to the directory of the banlist data.
+pub const EXT_DIR: &str = "https://ygo.anihelp.co.uk/public/config3";
+/// External [URL](https://ygo.anihelp.co.uk/public/config3/TCGCombiList.conf) to the TCG banlist
+/// data.
 pub const EXT_URL: &str = "https://ygo.anihelp.co.uk/public/config3/TCGCombiList.conf";
+/// File names (without the `.conf` extension) of the format banlists in [`EXT_DIR`] that are
+/// downloaded in addition to the TCG and OCG banlists.
+pub const FORMATS: [&str; 2] = ["Goat", "Edison"];
+
+/// Region or format a collection of banlists applies to.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BanlistSource {
+    Tcg,
+    Ocg,
+    /// A format banlist with the file name (without the `.conf` extension) in [`EXT_DIR`], e.g.
+    /// `Goat`.
+    Format(String),
+}
+
+impl BanlistSource {
+    /// Returns the TCG and OCG sources followed by all [`FORMATS`].
+    pub fn all() -> Vec<Self> {
+        let mut sources = vec![BanlistSource::Tcg, BanlistSource::Ocg];
+        sources.extend(
+            FORMATS
+                .iter()
+                .map(|format| BanlistSource::Format(format.to_string())),
+        );
+
+        return sources;
+    }
+
+    /// External URL to the banlist data of the source.
+    pub fn url(&self) -> String {
+        match self {
+            BanlistSource::Tcg => EXT_URL.to_string(),
+            BanlistSource::Ocg => format!("{}/OCGCombiList.conf", EXT_DIR),
+            BanlistSource::Format(file) => format!("{}/{}.conf", EXT_DIR, file),
+        }
+    }
+}
+
+impl fmt::Display for BanlistSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BanlistSource::Tcg => write!(f, "TCG"),
+            BanlistSource::Ocg => write!(f, "OCG"),
+            BanlistSource::Format(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Parser for the anihelp banlist format, generated from `banlist.pest`.
+#[derive(Parser)]
+#[grammar = "ext_data/banlists/banlist.pest"]
+struct BanlistParser;
+
+/// Position and reason of malformed banlist data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BanlistParseError {
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error, starting at 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for BanlistParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid banlist data at {}:{}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for BanlistParseError {}
+
+impl From<Error<Rule>> for BanlistParseError {
+    fn from(error: Error<Rule>) -> Self {
+        let (line, column) = match error.line_col {
+            LineColLocation::Pos(pos) => pos,
+            LineColLocation::Span(start, _) => start,
+        };
+
+        BanlistParseError {
+            line: line,
+            column: column,
+            message: error.variant.message().to_string(),
+        }
+    }
+}
 
-/// Returns a processed banlist.
+/// Returns processed banlists of a region.
+///
+/// Each banlist starts with a header, like `!2023.02 TCG` or `!01.02.2023 OCG`. Lines starting
+/// with `#` and trailing ` --` comments, which usually contain card names, are ignored. A
+/// `$whitelist` line makes every card that is not listed forbidden.
 ///
 /// # Arguments
 ///
-/// * `banlist` – Slice containing raw banlist data.
-pub fn parse(banlists: &str) -> BanlistsMetaType {
-    // Define regex for seperating the banlists in the TCGCombiList (all official TCG banlists)
-    let re_banlist_section = Regex::new(r"!.*\n(?:[\d]* [\d].*\n)+").unwrap();
+/// * `banlists` – Slice containing raw banlist data.
+pub fn parse(banlists: &str) -> Result<BanlistsMetaType, BanlistParseError> {
+    parse_rule(Rule::conf, banlists)
+}
 
-    // Define regex for detecting the banlist date
-    let re_date =
-        Regex::new(r"(?P<d>\d{2}).(?P<m>\d{2}).(?P<y>\d{4})|(?P<Y>\d{4}).(?P<M>\d{2})").unwrap();
+/// Returns processed banlists of a format, like [`parse`]. Headers can also consist of a date
+/// followed by a name or just the name of the format, like `!GOAT`. Banlists without a date are
+/// stored at [`NaiveDate::MIN`], so there can only be one of them.
+///
+/// # Arguments
+///
+/// * `banlists` – Slice containing raw banlist data.
+pub fn parse_format(banlists: &str) -> Result<BanlistsMetaType, BanlistParseError> {
+    parse_rule(Rule::format_conf, banlists)
+}
+
+/// Returns the banlist that was in effect on `date`, together with the date it came into effect.
+/// Returns `None` if `date` is before the first banlist.
+///
+/// # Arguments
+///
+/// * `banlists` – Processed banlists, usually [`Cache::banlists`][`crate::utils::Cache::banlists`].
+/// * `date` – Date to look up.
+pub fn banlist_at(banlists: &BanlistsMetaType, date: NaiveDate) -> Option<(&NaiveDate, &Banlist)> {
+    banlists.range(..=date).next_back()
+}
 
+/// Returns processed banlists, starting at the grammar rule `rule`.
+fn parse_rule(rule: Rule, banlists: &str) -> Result<BanlistsMetaType, BanlistParseError> {
     // Define container for banlists in the format: <date, <card: limit, ...>>
-    let mut banlist_map: BanlistsMetaType = HashMap::new();
-
-    // Parse the given banlist file
-    for banlist in re_banlist_section.find_iter(banlists) {
-        let mut map: BanlistElementType = HashMap::new();
-
-        // Split the banlist by newlines
-        let split: Vec<&str> = banlist.as_str().split("\n").collect();
-
-        // Iterate over the banlist's cards, so everything in the split Vec but the first element, which is the date
-        for card in &split[1..] {
-            // Split the card by whitespaces
-            let card_split: Vec<&str> = card.split(" ").collect();
-
-            // Insert the card's banlist data into the banlist_map if at least 2 elements, an id and a limit value, are given
-            if card_split.len() >= 2 {
-                map.insert(
-                    card_split[0].parse::<u32>().unwrap(),
-                    card_split[1].parse::<u8>().unwrap(),
-                );
+    let mut banlist_map: BanlistsMetaType = BTreeMap::new();
+
+    let conf = BanlistParser::parse(rule, banlists)?.next().unwrap();
+
+    for banlist in conf
+        .into_inner()
+        .filter(|pair| pair.as_rule() == Rule::banlist || pair.as_rule() == Rule::format_banlist)
+    {
+        let (line, column) = banlist.line_col();
+        let mut date = NaiveDate::MIN;
+        let mut map = Banlist::new();
+
+        for pair in banlist.into_inner() {
+            match pair.as_rule() {
+                Rule::date => date = parse_date(pair)?,
+                Rule::whitelist => map.set_whitelist(true),
+                Rule::card => {
+                    let (line, column) = pair.line_col();
+                    let mut card = pair.into_inner();
+                    // Both values are limited to a few digits by the grammar
+                    let id = card.next().unwrap().as_str().parse::<u32>().unwrap();
+                    let limit = card.next().unwrap().as_str().parse::<u8>().unwrap();
+
+                    let status = LimitStatus::from_copies(limit).ok_or(BanlistParseError {
+                        line: line,
+                        column: column,
+                        message: format!("invalid limit {} of card {}", limit, id),
+                    })?;
+                    map.set_status(id, status);
+                }
+                _ => (),
             }
         }
 
-        // Get the banlist date
-        let date_captures = re_date.captures(split[0]).unwrap();
-        let date = match (
-            date_captures.name("d").is_some(),
-            date_captures.name("Y").is_some(),
-        ) {
-            (true, false) => vec![
-                date_captures["y"].to_string(),
-                date_captures["m"].to_string(),
-                date_captures["d"].to_string(),
-            ]
-            .join("/"),
-            (false, true) => vec![
-                date_captures["Y"].to_string(),
-                date_captures["M"].to_string(),
-                "01".to_string(),
-            ]
-            .join("/"),
-            _ => "0000/00/00".to_string(),
-        };
+        // Undated banlists would silently replace each other
+        if date == NaiveDate::MIN && banlist_map.contains_key(&date) {
+            return Err(BanlistParseError {
+                line: line,
+                column: column,
+                message: "more than one banlist without a date".to_string(),
+            });
+        }
 
         // Add collected data to banlist_map
         banlist_map.insert(date, map);
     }
 
-    return banlist_map;
+    return Ok(banlist_map);
 }
+
+/// Returns the date of a banlist. Dates without a day are set to the first day of the month.
+fn parse_date(date: Pair<Rule>) -> Result<NaiveDate, BanlistParseError> {
+    let (line, column) = date.line_col();
+    let mut day = 1;
+    let mut month = 0;
+    let mut year = 0;
+
+    // All parts consist of digits only according to the grammar
+    for part in date.into_inner() {
+        match part.as_rule() {
+            Rule::day => day = part.as_str().parse().unwrap(),
+            Rule::month => month = part.as_str().parse().unwrap(),
+            Rule::year => year = part.as_str().parse().unwrap(),
+            _ => (),
+        }
+    }
+
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(BanlistParseError {
+        line: line,
+        column: column,
+        message: format!("invalid date {}/{}/{}", year, month, day),
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,236 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+use crate::ext_data::cardinfo::{Attribute, Card, CardinfoMetaType, Race};
+use crate::test_fixtures;
+
+const BANLISTS: &str = "#[2023.02 TCG][01.12.2022 TCG]\r
+#Banlists from the TCG\r
+!2023.02 TCG\r
+#Forbidden\r
+14558127 0 --Ash Blossom & Joyous Spring\r
+91869203 1 --Amazoness Archer\r
+\r
+#Semi-Limited\r
+2295440 2\r
+!01.12.2022 TCG\r
+14558127 3 --Ash Blossom & Joyous Spring\r
+";
+
+#[test]
+fn test_parse() {
+    let banlists = parse(BANLISTS).unwrap();
+
+    assert_eq!(banlists.len(), 2);
+    assert_eq!(
+        banlists[&NaiveDate::from_ymd_opt(2023, 2, 1).unwrap()],
+        Banlist::from_iter([
+            (14558127, LimitStatus::Forbidden),
+            (91869203, LimitStatus::Limited),
+            (2295440, LimitStatus::SemiLimited),
+        ])
+    );
+    assert_eq!(
+        banlists[&NaiveDate::from_ymd_opt(2022, 12, 1).unwrap()],
+        Banlist::from_iter([(14558127, LimitStatus::Unlimited)])
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse("!2023.02 TCG\n14558127 0\n91869203 one\n").unwrap_err();
+
+    assert_eq!(error.line, 3);
+    assert_eq!(error.column, 10);
+
+    assert!(parse("!February TCG\n14558127 0\n").is_err());
+
+    let error = parse("!2023.02 TCG\n14558127 0\n91869203 4\n").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 1));
+
+    let error = parse("#Banlists\n!31.02.2023 TCG\n14558127 0\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+#[test]
+fn test_banlist_at() {
+    let banlists = parse(BANLISTS).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(banlist_at(&banlists, date(2022, 11, 30)), None);
+    assert_eq!(
+        banlist_at(&banlists, date(2022, 12, 1)).unwrap().0,
+        &date(2022, 12, 1)
+    );
+    assert_eq!(
+        banlist_at(&banlists, date(2023, 1, 31)).unwrap().0,
+        &date(2022, 12, 1)
+    );
+    assert_eq!(
+        banlist_at(&banlists, date(2024, 6, 1)).unwrap().0,
+        &date(2023, 2, 1)
+    );
+}
+
+/// Card data that only contains Ash Blossom & Joyous Spring.
+fn init_cardinfo() -> CardinfoMetaType {
+    let mut cardinfo = CardinfoMetaType::new();
+    cardinfo.insert(
+        14558127,
+        Card {
+            def: Some(1800),
+            level: Some(3),
+            r#type: "Zombie".to_string(),
+            race: Some(Race::Zombie),
+            attribute: Some(Attribute::Fire),
+            ..test_fixtures::card(
+                14558127,
+                "Ash Blossom & Joyous Spring",
+                "Tuner Effect Monster",
+            )
+        },
+    );
+
+    return cardinfo;
+}
+
+#[test]
+fn test_diff() {
+    let banlists = parse(BANLISTS).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let cardinfo = init_cardinfo();
+
+    let changes = diff(&banlists, &cardinfo, date(2022, 12, 24), date(2023, 2, 10));
+
+    assert_eq!(changes.from, Some(date(2022, 12, 1)));
+    assert_eq!(changes.to, Some(date(2023, 2, 1)));
+    assert_eq!(
+        changes.forbidden(),
+        vec![&BanlistChange {
+            id: 14558127,
+            name: Some("Ash Blossom & Joyous Spring".to_string()),
+            previous: LimitStatus::Unlimited,
+            new: LimitStatus::Forbidden,
+        }]
+    );
+    assert_eq!(changes.limited()[0].id, 91869203);
+    assert_eq!(changes.limited()[0].name, None);
+    assert_eq!(changes.semi_limited()[0].id, 2295440);
+    assert_eq!(changes.changes.len(), 3);
+
+    let reverse = diff(&banlists, &cardinfo, date(2023, 2, 1), date(2022, 12, 1));
+    assert_eq!(reverse.unlimited().len(), 3);
+
+    let before = diff(&banlists, &cardinfo, date(2000, 1, 1), date(2022, 12, 1));
+    assert_eq!(before.from, None);
+    assert!(before.changes.is_empty());
+}
+
+#[test]
+fn test_diff_whitelist() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let mut cardinfo = init_cardinfo();
+    cardinfo.insert(
+        5318639,
+        test_fixtures::card(5318639, "Mystical Space Typhoon", "Spell Card"),
+    );
+
+    let mut banlists = parse(BANLISTS).unwrap();
+    let mut whitelist = Banlist::from_iter([(5318639, LimitStatus::Limited)]);
+    whitelist.set_whitelist(true);
+    banlists.insert(date(2023, 6, 1), whitelist);
+
+    let changes = diff(&banlists, &cardinfo, date(2023, 2, 1), date(2023, 6, 1));
+    assert!(!changes.from_whitelist);
+    assert!(changes.to_whitelist);
+    // Cards that are not on the whitelist become forbidden
+    assert_eq!(changes.limited()[0].id, 5318639);
+    assert_eq!(
+        changes
+            .forbidden()
+            .iter()
+            .map(|change| change.id)
+            .collect::<Vec<u32>>(),
+        vec![2295440, 91869203]
+    );
+    assert_eq!(changes.changes.len(), 3);
+}
+
+#[test]
+fn test_limit_status() {
+    let banlists = parse(BANLISTS).unwrap();
+    let banlist = &banlists[&NaiveDate::from_ymd_opt(2023, 2, 1).unwrap()];
+
+    assert_eq!(banlist.status_of(14558127), LimitStatus::Forbidden);
+    assert_eq!(banlist.status_of(2295440).max_copies(), 2);
+    assert_eq!(banlist.status_of(1), LimitStatus::Unlimited);
+    assert_eq!(LimitStatus::from_copies(1), Some(LimitStatus::Limited));
+    assert_eq!(LimitStatus::from_copies(4), None);
+}
+
+#[test]
+fn test_lflist() {
+    let banlists = parse(BANLISTS).unwrap();
+    let banlist = &banlists[&NaiveDate::from_ymd_opt(2023, 2, 1).unwrap()];
+    let cardinfo = init_cardinfo();
+
+    assert_eq!(
+        banlist.to_lflist("2023.02 TCG", &cardinfo),
+        "!2023.02 TCG\n14558127 0 --Ash Blossom & Joyous Spring\n91869203 1\n2295440 2\n"
+    );
+
+    let mut whitelist = Banlist::from_iter([(14558127, LimitStatus::Unlimited)]);
+    whitelist.set_whitelist(true);
+    assert_eq!(
+        whitelist.to_lflist("Sealed", &cardinfo),
+        "!Sealed\n$whitelist\n14558127 3 --Ash Blossom & Joyous Spring\n"
+    );
+}
+
+#[test]
+fn test_sources() {
+    let ocg = parse("!2023.01 OCG\n14558127 1\n").unwrap();
+    assert_eq!(
+        ocg[&NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()].status_of(14558127),
+        LimitStatus::Limited
+    );
+
+    let goat =
+        parse_format("#Goat format\n!GOAT\n$whitelist\n5318639 1 --Mystical Space Typhoon\n")
+            .unwrap();
+    let banlist = &goat[&NaiveDate::MIN];
+    assert!(banlist.is_whitelist());
+    assert_eq!(banlist.status_of(5318639), LimitStatus::Limited);
+    assert_eq!(banlist.status_of(14558127), LimitStatus::Forbidden);
+
+    // Labels are only allowed for formats
+    assert!(parse("!GOAT\n5318639 1\n").is_err());
+
+    let edison = parse_format("!2010.03 Edison\n5318639 1\n").unwrap();
+    assert!(edison.contains_key(&NaiveDate::from_ymd_opt(2010, 3, 1).unwrap()));
+
+    let error = parse_format("!GOAT\n5318639 1\n!Edison\n5318639 2\n").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 1));
+
+    assert_eq!(
+        BanlistSource::Format("Goat".to_string()).url(),
+        "https://ygo.anihelp.co.uk/public/config3/Goat.conf"
+    );
+    assert_eq!(BanlistSource::all().len(), 2 + FORMATS.len());
+}
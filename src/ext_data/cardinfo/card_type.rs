@@ -0,0 +1,277 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of card frame. A card has one main frame, Pendulum monsters additionally have the
+/// [`FrameKind::Pendulum`] frame.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameKind {
+    Normal,
+    Effect,
+    Ritual,
+    Fusion,
+    Synchro,
+    Xyz,
+    Link,
+    Pendulum,
+    Spell,
+    Trap,
+    Token,
+    Skill,
+}
+
+/// Monster abilities that are part of the card type.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ability {
+    Tuner,
+    Flip,
+    Gemini,
+    Spirit,
+    Toon,
+    Union,
+}
+
+/// Property of a Spell or Trap card.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Property {
+    Normal,
+    Continuous,
+    Counter,
+    Equip,
+    Field,
+    QuickPlay,
+    Ritual,
+}
+
+/// Type of a monster, e.g. Spellcaster.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Race {
+    Aqua,
+    Beast,
+    BeastWarrior,
+    CreatorGod,
+    Cyberse,
+    Dinosaur,
+    DivineBeast,
+    Dragon,
+    Fairy,
+    Fiend,
+    Fish,
+    Illusion,
+    Insect,
+    Machine,
+    Plant,
+    Psychic,
+    Pyro,
+    Reptile,
+    Rock,
+    SeaSerpent,
+    Spellcaster,
+    Thunder,
+    Warrior,
+    WingedBeast,
+    Wyrm,
+    Zombie,
+}
+
+/// Attribute of a monster.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Attribute {
+    Dark,
+    Divine,
+    Earth,
+    Fire,
+    Light,
+    Water,
+    Wind,
+}
+
+/// Parsed card type, e.g. `Synchro Tuner Effect Monster`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CardKind {
+    /// Main frame of the card. This is never [`FrameKind::Pendulum`], see
+    /// [`pendulum`][`CardKind::pendulum`].
+    pub frame: FrameKind,
+    pub pendulum: bool,
+    pub abilities: Vec<Ability>,
+}
+
+impl CardKind {
+    /// Parses a card type as it is named in the YGOPRODECK API. Unknown parts are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `card_type` – Card type, e.g. `XYZ Pendulum Effect Monster` or `Spell Card`.
+    pub fn parse(card_type: &str) -> Self {
+        let words: Vec<&str> = card_type.split_whitespace().collect();
+        let has = |word: &str| words.iter().any(|w| w.eq_ignore_ascii_case(word));
+
+        let frame = if has("Spell") {
+            FrameKind::Spell
+        } else if has("Trap") {
+            FrameKind::Trap
+        } else if has("Skill") {
+            FrameKind::Skill
+        } else if has("Token") {
+            FrameKind::Token
+        } else if has("Fusion") {
+            FrameKind::Fusion
+        } else if has("Synchro") {
+            FrameKind::Synchro
+        } else if has("XYZ") {
+            FrameKind::Xyz
+        } else if has("Link") {
+            FrameKind::Link
+        } else if has("Ritual") {
+            FrameKind::Ritual
+        } else if has("Normal") {
+            FrameKind::Normal
+        } else {
+            FrameKind::Effect
+        };
+
+        let abilities = [
+            ("Tuner", Ability::Tuner),
+            ("Flip", Ability::Flip),
+            ("Gemini", Ability::Gemini),
+            ("Spirit", Ability::Spirit),
+            ("Toon", Ability::Toon),
+            ("Union", Ability::Union),
+        ]
+        .iter()
+        .filter(|(word, _)| has(word))
+        .map(|(_, ability)| *ability)
+        .collect();
+
+        Self {
+            frame: frame,
+            pendulum: has("Pendulum"),
+            abilities: abilities,
+        }
+    }
+
+    /// Whether or not the card has the frame `kind`. For Pendulum monsters this is true for both
+    /// their main frame and [`FrameKind::Pendulum`].
+    pub fn has_frame(&self, kind: FrameKind) -> bool {
+        self.frame == kind || (kind == FrameKind::Pendulum && self.pendulum)
+    }
+
+    /// Whether or not the card has the ability.
+    pub fn has_ability(&self, ability: Ability) -> bool {
+        self.abilities.contains(&ability)
+    }
+
+    /// Whether or not the card is a monster.
+    pub fn is_monster(&self) -> bool {
+        !matches!(
+            self.frame,
+            FrameKind::Spell | FrameKind::Trap | FrameKind::Skill
+        )
+    }
+
+    /// Whether or not the card is played from the extra deck.
+    pub fn is_extra_deck(&self) -> bool {
+        matches!(
+            self.frame,
+            FrameKind::Fusion | FrameKind::Synchro | FrameKind::Xyz | FrameKind::Link
+        )
+    }
+}
+
+impl Property {
+    /// Parses a Spell or Trap property as it is named in the YGOPRODECK API, e.g. `Quick-Play`.
+    pub fn parse(property: &str) -> Option<Self> {
+        match property {
+            "Normal" => Some(Property::Normal),
+            "Continuous" => Some(Property::Continuous),
+            "Counter" => Some(Property::Counter),
+            "Equip" => Some(Property::Equip),
+            "Field" => Some(Property::Field),
+            "Quick-Play" => Some(Property::QuickPlay),
+            "Ritual" => Some(Property::Ritual),
+            _ => None,
+        }
+    }
+}
+
+impl Race {
+    /// Parses a monster type as it is named in the YGOPRODECK API, e.g. `Winged Beast`.
+    pub fn parse(race: &str) -> Option<Self> {
+        match race {
+            "Aqua" => Some(Race::Aqua),
+            "Beast" => Some(Race::Beast),
+            "Beast-Warrior" => Some(Race::BeastWarrior),
+            "Creator-God" | "Creator God" => Some(Race::CreatorGod),
+            "Cyberse" => Some(Race::Cyberse),
+            "Dinosaur" => Some(Race::Dinosaur),
+            "Divine-Beast" => Some(Race::DivineBeast),
+            "Dragon" => Some(Race::Dragon),
+            "Fairy" => Some(Race::Fairy),
+            "Fiend" => Some(Race::Fiend),
+            "Fish" => Some(Race::Fish),
+            "Illusion" => Some(Race::Illusion),
+            "Insect" => Some(Race::Insect),
+            "Machine" => Some(Race::Machine),
+            "Plant" => Some(Race::Plant),
+            "Psychic" => Some(Race::Psychic),
+            "Pyro" => Some(Race::Pyro),
+            "Reptile" => Some(Race::Reptile),
+            "Rock" => Some(Race::Rock),
+            "Sea Serpent" => Some(Race::SeaSerpent),
+            "Spellcaster" => Some(Race::Spellcaster),
+            "Thunder" => Some(Race::Thunder),
+            "Warrior" => Some(Race::Warrior),
+            "Winged Beast" => Some(Race::WingedBeast),
+            "Wyrm" => Some(Race::Wyrm),
+            "Zombie" => Some(Race::Zombie),
+            _ => None,
+        }
+    }
+}
+
+impl Attribute {
+    /// Parses an attribute as it is named in the YGOPRODECK API, e.g. `DARK`.
+    pub fn parse(attribute: &str) -> Option<Self> {
+        match attribute.to_ascii_uppercase().as_str() {
+            "DARK" => Some(Attribute::Dark),
+            "DIVINE" => Some(Attribute::Divine),
+            "EARTH" => Some(Attribute::Earth),
+            "FIRE" => Some(Attribute::Fire),
+            "LIGHT" => Some(Attribute::Light),
+            "WATER" => Some(Attribute::Water),
+            "WIND" => Some(Attribute::Wind),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Attribute::Dark => write!(f, "DARK"),
+            Attribute::Divine => write!(f, "DIVINE"),
+            Attribute::Earth => write!(f, "EARTH"),
+            Attribute::Fire => write!(f, "FIRE"),
+            Attribute::Light => write!(f, "LIGHT"),
+            Attribute::Water => write!(f, "WATER"),
+            Attribute::Wind => write!(f, "WIND"),
+        }
+    }
+}
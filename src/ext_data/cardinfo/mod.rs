@@ -15,9 +15,15 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+/// Structured card types, monster types and attributes.
+mod card_type;
+
+pub use card_type::{Ability, Attribute, CardKind, FrameKind, Property, Race};
+
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::ext_data::cardsets::Printing;
 use crate::utils::http::CardSetMapType;
 
 /// Type contained in a processed cardinfo binary file.
@@ -63,42 +69,67 @@ pub struct YGOPDData {
 pub struct Card {
     pub id: u32,
     pub name: String,
+    /// Card type as it is named in the card data, e.g. `Synchro Tuner Monster`.
     pub card_type: String,
+    /// Parsed [`card_type`][`Card::card_type`].
+    pub kind: CardKind,
     pub description: String,
     pub atk: Option<u32>,
     pub def: Option<u32>,
     pub level: Option<u8>,
+    /// Monster type or Spell/Trap property as it is named in the card data, e.g. `Spellcaster`.
     pub r#type: String,
-    pub attribute: Option<String>,
+    /// Parsed monster type, `None` for Spells, Traps and Skills.
+    pub race: Option<Race>,
+    /// Parsed Spell/Trap property, `None` for monsters.
+    pub property: Option<Property>,
+    pub attribute: Option<Attribute>,
     pub archetype: Option<String>,
     pub pend_scale: Option<u8>,
     pub link_rating: Option<u8>,
 }
 
+impl Card {
+    /// Whether or not the card is played from the extra deck.
+    pub fn is_extra_deck(&self) -> bool {
+        self.kind.is_extra_deck()
+    }
+}
+
 /// Returns a processed cardinfo map.
 ///
 /// # Arguments
 ///
 /// * `cardinfo` – Slice containing raw cardinfo json data.
 ///
-/// * `card_set_map` – HashMap to be populated with card ids.
+/// * `card_set_map` – HashMap to be populated with card printings.
 pub fn parse(cardinfo: &str, card_set_map: &mut CardSetMapType) -> CardinfoMetaType {
     let mut cardinfo_map: CardinfoMetaType = HashMap::new();
 
     // Iterate of cards in data
     for card in serde_json::from_str::<YGOPDData>(cardinfo).unwrap().data {
+        let kind = CardKind::parse(&card.r#type);
+        let (race, property) = if kind.is_monster() {
+            (Race::parse(&card.race), None)
+        } else {
+            (None, Property::parse(&card.race))
+        };
+
         cardinfo_map.insert(
             card.id,
             Card {
                 id: card.id,
                 name: card.name,
                 card_type: card.r#type,
+                kind: kind,
                 description: card.desc,
                 atk: card.atk,
                 def: card.def,
                 level: card.level,
-                attribute: card.attribute,
+                attribute: card.attribute.as_deref().and_then(Attribute::parse),
                 r#type: card.race,
+                race: race,
+                property: property,
                 archetype: card.archetype,
                 pend_scale: card.scale,
                 link_rating: card.linkval,
@@ -108,15 +139,21 @@ pub fn parse(cardinfo: &str, card_set_map: &mut CardSetMapType) -> CardinfoMetaT
         // Extract card_sets and put them into card_set_map
         if card.card_sets.is_some() {
             for card_set in card.card_sets.unwrap() {
+                let printing = Printing {
+                    id: card.id,
+                    set_name: card_set.set_name.clone(),
+                    code: card_set.set_code,
+                    rarity: card_set.set_rarity,
+                };
                 let val = card_set_map.get_mut(card_set.set_name.as_str());
 
                 // Check if there already is a Vec at val
                 if val.is_some() {
-                    if !val.as_ref().unwrap().contains(&card.id) {
-                        val.unwrap().push(card.id);
+                    if !val.as_ref().unwrap().contains(&printing) {
+                        val.unwrap().push(printing);
                     }
                 } else {
-                    card_set_map.insert(card_set.set_name, vec![card.id]);
+                    card_set_map.insert(card_set.set_name, vec![printing]);
                 }
             }
         }
@@ -124,3 +161,6 @@ pub fn parse(cardinfo: &str, card_set_map: &mut CardSetMapType) -> CardinfoMetaT
 
     return cardinfo_map;
 }
+
+#[cfg(test)]
+mod tests;
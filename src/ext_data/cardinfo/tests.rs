@@ -0,0 +1,73 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+#[test]
+fn test_card_kind() {
+    let kind = CardKind::parse("Synchro Pendulum Effect Monster");
+    assert_eq!(kind.frame, FrameKind::Synchro);
+    assert!(kind.pendulum);
+    assert!(kind.has_frame(FrameKind::Pendulum));
+    assert!(kind.is_extra_deck());
+
+    let kind = CardKind::parse("Flip Tuner Effect Monster");
+    assert_eq!(kind.frame, FrameKind::Effect);
+    assert_eq!(kind.abilities, vec![Ability::Tuner, Ability::Flip]);
+    assert!(!kind.is_extra_deck());
+
+    assert_eq!(CardKind::parse("XYZ Monster").frame, FrameKind::Xyz);
+    assert_eq!(
+        CardKind::parse("Normal Tuner Monster").frame,
+        FrameKind::Normal
+    );
+    assert_eq!(
+        CardKind::parse("Pendulum Effect Ritual Monster").frame,
+        FrameKind::Ritual
+    );
+    assert!(CardKind::parse("Gemini Monster").has_ability(Ability::Gemini));
+    assert!(!CardKind::parse("Spell Card").is_monster());
+    assert!(CardKind::parse("Token").is_monster());
+}
+
+#[test]
+fn test_parse() {
+    let cardinfo = r#"{"data": [
+        {"id": 46986414, "name": "Dark Magician", "type": "Normal Monster",
+         "desc": "", "atk": 2500, "def": 2100, "level": 7, "race": "Spellcaster",
+         "attribute": "DARK", "archetype": "Dark Magician"},
+        {"id": 5318639, "name": "Mystical Space Typhoon", "type": "Spell Card",
+         "desc": "", "race": "Quick-Play",
+         "card_sets": [{"set_name": "Magic Ruler", "set_code": "MRL-047",
+                        "set_rarity": "Ultra Rare"}]}
+    ]}"#;
+    let mut card_set_map = CardSetMapType::new();
+    let cardinfo = parse(cardinfo, &mut card_set_map);
+
+    let dark_magician = &cardinfo[&46986414];
+    assert_eq!(dark_magician.kind.frame, FrameKind::Normal);
+    assert_eq!(dark_magician.race, Some(Race::Spellcaster));
+    assert_eq!(dark_magician.attribute, Some(Attribute::Dark));
+    assert_eq!(dark_magician.property, None);
+
+    let mst = &cardinfo[&5318639];
+    assert_eq!(mst.kind.frame, FrameKind::Spell);
+    assert_eq!(mst.race, None);
+    assert_eq!(mst.property, Some(Property::QuickPlay));
+    assert_eq!(card_set_map["Magic Ruler"][0].code, "MRL-047");
+    assert_eq!(card_set_map["Magic Ruler"][0].set_name, "Magic Ruler");
+}
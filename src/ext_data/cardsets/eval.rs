@@ -114,6 +114,7 @@ impl TaggedSet {
 /// Gets a list of the set names that belong to core boosters.
 ///
 /// Scrapes data from the Yugipedia pages [TCG Core Boosters](https://yugipedia.com/wiki/Category:TCG_Core_Boosters) and [International Core Boosters](https://yugipedia.com/wiki/Category:International_Core_Boosters).
+/// Pages that can not be loaded are skipped.
 fn get_core_boosters() -> Vec<String> {
     let mut core_boosters: Vec<String> = Vec::new();
 
@@ -121,7 +122,10 @@ fn get_core_boosters() -> Vec<String> {
         "https://yugipedia.com/wiki/Category:TCG_Core_Boosters",
         "https://yugipedia.com/wiki/Category:International_Core_Boosters",
     ] {
-        let raw_html = get_response(url).unwrap();
+        let raw_html = match get_response(url) {
+            Ok(raw_html) => raw_html,
+            Err(_) => continue,
+        };
 
         let document = scraper::Html::parse_document(raw_html.as_str());
         let selector = scraper::Selector::parse("div.mw-category li > a").unwrap();
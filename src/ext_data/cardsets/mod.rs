@@ -40,10 +40,22 @@ pub struct YGOPDSet {
     pub tcg_date: Option<String>,
 }
 
+/// A card as it is printed in a card set.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Printing {
+    pub id: u32,
+    /// Name of the card set the card is printed in.
+    pub set_name: String,
+    /// Set code of the card, e.g. `LOB-EN001`.
+    pub code: String,
+    pub rarity: String,
+}
+
 /// Representation of a processed card set.
 #[derive(Serialize, Deserialize)]
 pub struct Set {
     pub cards: Option<Vec<u32>>,
+    pub printings: Vec<Printing>,
     pub code: String,
     pub date: Option<String>,
     pub tags: Vec<String>,
@@ -55,26 +67,30 @@ pub struct Set {
 ///
 /// * `cardsets` – Slice containing raw cardset json data.
 ///
-/// * `card_set_map` – HashMap containing the cards in each card set.
+/// * `card_set_map` – HashMap containing the card printings in each card set.
 pub fn parse(cardsets: &str, card_set_map: CardSetMapType) -> CardsetsMetaType {
     let mut cardsets_map: CardsetsMetaType = HashMap::new();
 
     for cardset in serde_json::from_str::<Vec<YGOPDSet>>(cardsets).unwrap() {
-        let cards = {
-            // Get cards at set_name
-            let cards_tmp = card_set_map.get(&cardset.set_name);
-
-            if cards_tmp.is_some() {
-                Some(cards_tmp.unwrap().clone())
-            } else {
-                Some(vec![])
+        // Get printings at set_name
+        let printings = card_set_map
+            .get(&cardset.set_name)
+            .cloned()
+            .unwrap_or_default();
+
+        // Collect the ids of all printings without duplicates
+        let mut cards: Vec<u32> = Vec::new();
+        for printing in printings.iter() {
+            if !cards.contains(&printing.id) {
+                cards.push(printing.id);
             }
-        };
+        }
 
         cardsets_map.insert(
             cardset.set_name.clone(),
             Set {
-                cards: cards,
+                cards: Some(cards),
+                printings: printings,
                 code: cardset.set_code,
                 date: cardset.tcg_date,
                 tags: eval_tags(cardset.set_name),
@@ -26,3 +26,7 @@ pub mod utils;
 
 /// Reverse DNS style application identifier: `com.myujiku.ygo_destiny`.
 pub const APP_ID: &str = "com.myujiku.ygo_destiny";
+
+/// Card data shared by the unit tests.
+#[cfg(test)]
+mod test_fixtures;
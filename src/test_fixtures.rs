@@ -0,0 +1,123 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Card data shared by the unit tests of all modules.
+
+use crate::ext_data::cardinfo::{self, Attribute, Card, CardKind, CardinfoMetaType, Race};
+use crate::ext_data::cardsets::{Printing, Set};
+use crate::utils::http::CardSetMapType;
+use crate::utils::Cache;
+
+/// Returns a level 4 LIGHT Warrior with 0 ATK and DEF.
+///
+/// # Arguments
+///
+/// * `id` – Id of the card.
+/// * `name` – Name of the card.
+/// * `card_type` – Card type as it is named in the card data, e.g. `Effect Monster`.
+pub fn card(id: u32, name: &str, card_type: &str) -> Card {
+    Card {
+        id: id,
+        name: name.to_string(),
+        card_type: card_type.to_string(),
+        kind: CardKind::parse(card_type),
+        description: String::new(),
+        atk: Some(0),
+        def: Some(0),
+        level: Some(4),
+        r#type: "Warrior".to_string(),
+        race: Some(Race::Warrior),
+        property: None,
+        attribute: Some(Attribute::Light),
+        archetype: None,
+        pend_scale: None,
+        link_rating: None,
+    }
+}
+
+/// Parses card data in the format of the YGOPRODeck API.
+///
+/// # Arguments
+///
+/// * `json` – Raw cardinfo json data.
+pub fn parse_cardinfo(json: &str) -> CardinfoMetaType {
+    cardinfo::parse(json, &mut CardSetMapType::new())
+}
+
+/// Returns a printing of card `id` in `set_name` with the set code `<set_name>-EN<id>`.
+///
+/// # Arguments
+///
+/// * `id` – Id of the card.
+/// * `set_name` – Name of the card set.
+/// * `rarity` – Set rarity of the printing.
+pub fn printing(id: u32, set_name: &str, rarity: &str) -> Printing {
+    Printing {
+        id: id,
+        set_name: set_name.to_string(),
+        code: format!("{}-EN{:03}", set_name, id),
+        rarity: rarity.to_string(),
+    }
+}
+
+/// Inserts a card set into `cache`. The cards of the set are the cards of its printings.
+///
+/// # Arguments
+///
+/// * `cache` – Cache the set is inserted into.
+/// * `name` – Name of the set, which is also used as its set code.
+/// * `tags` – Tags of the set.
+/// * `printings` – Printings of the set.
+pub fn insert_set(cache: &mut Cache, name: &str, tags: &[&str], printings: Vec<Printing>) {
+    let mut cards: Vec<u32> = Vec::new();
+    for printing in printings.iter() {
+        if !cards.contains(&printing.id) {
+            cards.push(printing.id);
+        }
+    }
+
+    cache.cardsets.insert(
+        name.to_string(),
+        Set {
+            cards: Some(cards),
+            printings: printings,
+            code: name.to_string(),
+            date: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        },
+    );
+}
+
+/// Returns a cache with the cards of `json` and the sets they are printed in, with indexes.
+///
+/// # Arguments
+///
+/// * `json` – Raw cardinfo json data.
+pub fn cache_from_cardinfo(json: &str) -> Cache {
+    let mut cache = Cache::default();
+
+    let mut card_set_map = CardSetMapType::new();
+    cache.cardinfo = cardinfo::parse(json, &mut card_set_map);
+
+    for (name, printings) in card_set_map {
+        insert_set(&mut cache, &name, &[], printings);
+    }
+
+    cache.rebuild_indexes();
+
+    return cache;
+}
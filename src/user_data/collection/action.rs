@@ -15,30 +15,38 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use super::DraftSettings;
+use super::{DraftBox, DraftSession};
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+/// A draft that is part of the regular schedule of a collection.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct MainDraft {
     pub name: String,
+    /// Identifier that [`BonusDraft`]s can refer to.
     pub id: u8,
-    pub settings: DraftSettings,
+    pub settings: DraftBox,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+/// An additional draft. If `main_draft_id` is set, the bonus draft is only run if the
+/// [`MainDraft`] with that id has already been run.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct BonusDraft {
     pub name: String,
     pub main_draft_id: Option<u8>,
-    pub settings: DraftSettings,
+    pub settings: DraftBox,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+/// Cards that are added to a collection without drafting.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct BonusCard {
     pub name: String,
+    pub cards: Vec<u32>,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub enum Action {
     #[default]
     None,
@@ -46,3 +54,112 @@ pub enum Action {
     BonusDraft(BonusDraft),
     BonusCard(BonusCard),
 }
+
+/// A draft of an [`ActionSchedule`] that has been started, but not finished yet.
+#[derive(Serialize, Deserialize)]
+pub struct PendingDraft {
+    pub name: String,
+    /// Id of the draft if it is a [`MainDraft`]. The id counts as completed once the draft is
+    /// finished.
+    pub main_draft_id: Option<u8>,
+    pub session: DraftSession,
+}
+
+impl fmt::Debug for PendingDraft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingDraft")
+            .field("name", &self.name)
+            .field("main_draft_id", &self.main_draft_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Result of advancing an [`ActionSchedule`] by one action.
+pub enum ActionStep {
+    /// A draft was started or has not been finished yet. It is played with
+    /// [`Collection::play_draft`][`super::Collection::play_draft`] and finished with
+    /// [`Collection::finish_draft`][`super::Collection::finish_draft`].
+    Draft { name: String },
+    /// Bonus cards were added to the collection.
+    BonusCards { name: String, cards: Vec<u32> },
+    /// The action was skipped, because it has no draft settings or because the main draft of a
+    /// bonus draft has not been run yet.
+    Skipped { name: String },
+    /// All actions have already been executed.
+    Finished,
+}
+
+/// An ordered list of [`Action`]s that are executed one after another.
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct ActionSchedule {
+    pub actions: Vec<Action>,
+    /// Index of the next action.
+    position: usize,
+    /// Ids of all main drafts that have been run.
+    completed_main_drafts: Vec<u8>,
+    /// Draft that was started, but not finished yet.
+    pub(super) pending: Option<PendingDraft>,
+}
+
+impl ActionSchedule {
+    /// Constructs a new schedule that starts at the first action.
+    ///
+    /// # Arguments
+    ///
+    /// * `actions` – Actions in the order they are executed.
+    pub fn new(actions: Vec<Action>) -> Self {
+        Self {
+            actions: actions,
+            position: 0,
+            completed_main_drafts: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Index of the next action.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The action that is executed next, if any.
+    pub fn next_action(&self) -> Option<&Action> {
+        self.actions.get(self.position)
+    }
+
+    /// Whether or not all actions have been executed and no draft is pending.
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.actions.len() && self.pending.is_none()
+    }
+
+    /// The draft that was started, but not finished yet, if any.
+    pub fn pending_draft(&self) -> Option<&PendingDraft> {
+        self.pending.as_ref()
+    }
+
+    /// Whether or not the main draft with `id` has been run.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the main draft.
+    pub fn main_draft_completed(&self, id: u8) -> bool {
+        self.completed_main_drafts.contains(&id)
+    }
+
+    /// Moves on to the next action.
+    pub(super) fn advance(&mut self) {
+        if self.position < self.actions.len() {
+            self.position += 1;
+        }
+    }
+
+    /// Removes the pending draft and marks it as completed if it is a main draft.
+    pub(super) fn finish_pending(&mut self) {
+        if let Some(PendingDraft {
+            main_draft_id: Some(id),
+            ..
+        }) = self.pending.take()
+        {
+            self.completed_main_drafts.push(id);
+        }
+    }
+}
@@ -17,9 +17,37 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use serde::{Deserialize, Serialize};
 
+use crate::ext_data::cardsets::Printing;
+
 /// Wrapper for a card in a collection. Will contain more fields, like rarity, in the future.
 #[derive(Serialize, Deserialize, Clone, Default, Debug, Eq, Hash, PartialEq)]
 pub struct Card {
     pub id: u32,
+    /// Set code of the printing the card was pulled as, e.g. `LOB-EN001`. `None` if the printing
+    /// is unknown.
+    pub printing: Option<String>,
     // pub tags: Vec<String>,
 }
+
+impl Card {
+    /// Constructs a [`Card`] without a known printing.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id: id,
+            printing: None,
+        }
+    }
+}
+
+impl From<&Printing> for Card {
+    fn from(printing: &Printing) -> Self {
+        Self {
+            id: printing.id,
+            printing: Some(printing.code.clone()),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::user_data::collection::{Card, Change, ChangeContent, Collection, LAST_CHANGED_FORMAT};
+use crate::utils::card_gen::PackGenerator;
+use crate::utils::Cache;
+
+use super::{resolve_banlist, BattlePackSettings, DraftError};
+
+/// A running draft based on [`BattlePackSettings`].
+///
+/// Packs are opened one at a time and the cards of each pack are added to a [`Collection`] as a
+/// [`Change::Add`] tagged with the (zero based) pack number. See
+/// [`BattlePackSettings::layout`] for how the slots of a pack are filled.
+#[derive(Serialize, Deserialize)]
+pub struct BattlePackSession {
+    settings: BattlePackSettings,
+    /// Seed the pack generator was constructed with.
+    seed: u64,
+    generator: PackGenerator,
+    /// Cards of all opened packs.
+    packs: Vec<Vec<u32>>,
+}
+
+impl BattlePackSession {
+    /// Starts a new Battle Pack draft with a random seed.
+    /// Returns [`DraftError::UnknownBanlist`] if the banlist of the settings can not be found.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` – Settings of the draft.
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn new(settings: &BattlePackSettings, cache: &Cache) -> Result<Self, DraftError> {
+        Self::with_seed(settings, cache, rand::random())
+    }
+
+    /// Starts a new Battle Pack draft that opens the same packs for the same `seed` and settings.
+    /// Same arguments as [`new`][`Self::new`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the draft. See [`seed_from_str`][`crate::utils::card_gen::seed_from_str`]
+    /// to use a string.
+    pub fn with_seed(
+        settings: &BattlePackSettings,
+        cache: &Cache,
+        seed: u64,
+    ) -> Result<Self, DraftError> {
+        let banlist = resolve_banlist(&settings.banlist, cache)?;
+        let printings = cache.get_printings_from_sets(settings.sets.clone());
+
+        return Ok(Self {
+            settings: settings.clone(),
+            seed: seed,
+            generator: PackGenerator::from_seed_banlist(
+                printings,
+                settings.layout(),
+                &banlist,
+                seed,
+            ),
+            packs: Vec::new(),
+        });
+    }
+
+    /// Settings the draft was started with.
+    pub fn settings(&self) -> &BattlePackSettings {
+        &self.settings
+    }
+
+    /// Seed the draft was started with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Cards of all packs that have been opened so far.
+    pub fn packs(&self) -> &Vec<Vec<u32>> {
+        &self.packs
+    }
+
+    /// Whether or not all packs have been opened.
+    pub fn is_finished(&self) -> bool {
+        self.packs.len() >= self.settings.packs_num
+    }
+
+    /// Opens the next pack, adds its cards to `collection` and returns them.
+    ///
+    /// The cards are ordered by slot: commons first, then rares and foils last.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` – Collection the opened cards are added to.
+    pub fn open_pack(&mut self, collection: &mut Collection) -> Result<Vec<u32>, DraftError> {
+        if self.is_finished() {
+            return Err(DraftError::Finished);
+        }
+
+        let printings = self.generator.generate();
+        let pack: Vec<u32> = printings.iter().map(|printing| printing.id).collect();
+
+        collection.add_change(Change::Add(ChangeContent::new(
+            printings.iter().map(Card::from).collect(),
+            format!("{}", Utc::now().format(LAST_CHANGED_FORMAT)),
+            Some(self.packs.len() as u16),
+        )));
+
+        self.packs.push(pack.clone());
+
+        return Ok(pack);
+    }
+
+    /// Opens all remaining packs. Returns the cards of all packs opened by this call.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` – Collection the opened cards are added to.
+    pub fn open_all(&mut self, collection: &mut Collection) -> Vec<Vec<u32>> {
+        let mut packs = Vec::new();
+
+        while let Ok(pack) = self.open_pack(collection) {
+            packs.push(pack);
+        }
+
+        return packs;
+    }
+}
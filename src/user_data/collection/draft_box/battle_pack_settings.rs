@@ -17,5 +17,91 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Default, Debug)]
-pub struct BattlePackSettings {}
+use crate::user_data::BanlistRef;
+
+use crate::utils::card_gen::{PackLayout, RaritySlot};
+use crate::utils::Cache;
+
+/// Tag that card sets which can be used in a Battle Pack draft are marked with.
+pub const BATTLE_PACK_TAG: &str = "Battle Pack";
+
+/// Rarities of the cards in the foil slots of a Battle Pack.
+const FOIL_RARITIES: [&str; 3] = ["Mosaic Rare", "Starfoil Rare", "Shatterfoil Rare"];
+
+/// Settings of a draft where each player opens a number of Battle Packs.
+///
+/// Every pack consists of `rares_num` rares, `foils_num` foils and is filled up with commons until
+/// it contains `pack_size` cards.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BattlePackSettings {
+    /// Names of the Battle Pack sets the packs are opened from.
+    pub sets: Vec<String>,
+    /// Number of packs each player opens.
+    pub packs_num: usize,
+    /// Number of cards in each pack.
+    pub pack_size: usize,
+    /// Number of rare slots in each pack.
+    pub rares_num: usize,
+    /// Number of foil slots in each pack.
+    pub foils_num: usize,
+    /// Banlist of the draft. Forbidden cards are never generated, limited and semi-limited cards
+    /// at most once or twice.
+    pub banlist: BanlistRef,
+}
+
+impl BattlePackSettings {
+    /// Number of common slots in each pack.
+    pub fn commons_num(&self) -> usize {
+        self.pack_size
+            .saturating_sub(self.rares_num)
+            .saturating_sub(self.foils_num)
+    }
+
+    /// Returns the layout of the packs.
+    ///
+    /// Common and rare slots are generated from printings with the rarity `Common` and `Rare`
+    /// respectively. Foil slots are generated from `Mosaic Rare`, `Starfoil Rare` and
+    /// `Shatterfoil Rare` printings. Slots without any matching printings are generated from all
+    /// cards of the sets instead.
+    pub fn layout(&self) -> PackLayout {
+        PackLayout {
+            slots: vec![
+                RaritySlot::fixed(self.commons_num(), &["Common"]),
+                RaritySlot::fixed(self.rares_num, &["Rare"]),
+                RaritySlot::fixed(self.foils_num, &FOIL_RARITIES),
+            ],
+        }
+    }
+
+    /// Returns the names of all sets in `cache` that can be used in a Battle Pack draft, sorted
+    /// alphabetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn available_sets(cache: &Cache) -> Vec<String> {
+        let mut sets: Vec<String> = cache
+            .cardsets
+            .iter()
+            .filter(|(_, set)| set.tags.iter().any(|tag| tag == BATTLE_PACK_TAG))
+            .map(|(name, _)| name.clone())
+            .collect();
+        sets.sort_unstable();
+
+        return sets;
+    }
+}
+
+/// Defaults to the layout of a real Battle Pack: 3 commons, 1 rare and 1 foil.
+impl Default for BattlePackSettings {
+    fn default() -> Self {
+        Self {
+            sets: Vec::new(),
+            packs_num: 10,
+            pack_size: 5,
+            rares_num: 1,
+            foils_num: 1,
+            banlist: BanlistRef::None,
+        }
+    }
+}
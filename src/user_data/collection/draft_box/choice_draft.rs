@@ -0,0 +1,263 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use chrono::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::user_data::collection::{Card, Change, ChangeContent, Collection, LAST_CHANGED_FORMAT};
+use crate::utils::card_gen::CardGenerator;
+use crate::utils::Cache;
+
+use super::{cards_from_sets, resolve_banlist, ChoiceSettings, DraftError, Drafter, SetRotation};
+
+/// Number of times each card can show up during a choice draft, unless the banlist of the draft
+/// allows fewer copies.
+const DUPLICATES: usize = 3;
+
+/// A running draft based on [`ChoiceSettings`].
+///
+/// Each round the player is offered `choices_num` choices of `cards_num` cards and has to select
+/// exactly `selections_num` of them. The cards of the selected choices are added to a
+/// [`Collection`] as a [`Change::Add`] tagged with the (zero based) round number.
+///
+/// If [`SetRotation::Enabled(n)`][`SetRotation::Enabled`] is set, the choices are generated from
+/// the next group of `sets` every `n` rounds, starting over after the last group. Otherwise all
+/// groups are combined into one card pool.
+#[derive(Serialize, Deserialize)]
+pub struct ChoiceDraftSession {
+    settings: ChoiceSettings,
+    /// Seed all card generators are derived from.
+    seed: u64,
+    /// One generator per group of sets, or a single one if rotation is disabled.
+    generators: Vec<CardGenerator>,
+    /// Current round, which is also the number of completed rounds.
+    round: usize,
+    /// Choices of the current round.
+    choices: Vec<Vec<u32>>,
+    /// Choices of all completed rounds. Used for undoing selections.
+    history: Vec<Vec<Vec<u32>>>,
+}
+
+impl ChoiceDraftSession {
+    /// Starts a new choice draft with a random seed and generates the choices of the first round.
+    /// Returns [`DraftError::UnknownBanlist`] or [`DraftError::UnknownSet`] if the banlist or one
+    /// of the sets of the settings can not be found.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` – Settings of the draft.
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn new(settings: &ChoiceSettings, cache: &Cache) -> Result<Self, DraftError> {
+        Self::with_seed(settings, cache, rand::random())
+    }
+
+    /// Starts a new choice draft that offers the same choices for the same `seed` and settings.
+    /// Same arguments as [`new`][`Self::new`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the draft. See [`seed_from_str`][`crate::utils::card_gen::seed_from_str`]
+    /// to use a string.
+    pub fn with_seed(
+        settings: &ChoiceSettings,
+        cache: &Cache,
+        seed: u64,
+    ) -> Result<Self, DraftError> {
+        let mut seeds = ChaCha8Rng::seed_from_u64(seed);
+        let groups = match settings.rotate {
+            SetRotation::Disabled => vec![settings.sets.concat()],
+            SetRotation::Enabled(_) => settings.sets.clone(),
+        };
+        let banlist = resolve_banlist(&settings.banlist, cache)?;
+        let mut generators = Vec::new();
+        for sets in groups {
+            generators.push(CardGenerator::from_seed_banlist(
+                cards_from_sets(sets, cache)?,
+                DUPLICATES,
+                &banlist,
+                seeds.gen(),
+            ));
+        }
+
+        let mut session = Self {
+            settings: settings.clone(),
+            seed: seed,
+            generators: generators,
+            round: 0,
+            choices: Vec::new(),
+            history: Vec::new(),
+        };
+        session.generate_choices();
+
+        return Ok(session);
+    }
+
+    /// Settings the draft was started with.
+    pub fn settings(&self) -> &ChoiceSettings {
+        &self.settings
+    }
+
+    /// Seed the draft was started with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The current (zero based) round.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Whether or not all rounds have been completed.
+    pub fn is_finished(&self) -> bool {
+        self.round >= self.settings.rounds_num
+    }
+
+    /// Choices of the current round. Empty if the draft is finished.
+    pub fn choices(&self) -> &Vec<Vec<u32>> {
+        &self.choices
+    }
+
+    /// Selects choices of the current round, adds their cards to `collection` and advances to the
+    /// next round. Returns the added cards.
+    ///
+    /// # Arguments
+    ///
+    /// * `selections` – Indices into [`choices`][`Self::choices`]. Exactly `selections_num`
+    /// distinct indices have to be given.
+    /// * `collection` – Collection the selected cards are added to.
+    pub fn select(
+        &mut self,
+        selections: &[usize],
+        collection: &mut Collection,
+    ) -> Result<Vec<u32>, DraftError> {
+        if self.is_finished() {
+            return Err(DraftError::Finished);
+        }
+
+        if selections.len() != self.settings.selections_num {
+            return Err(DraftError::WrongSelectionCount {
+                expected: self.settings.selections_num,
+                got: selections.len(),
+            });
+        }
+
+        for (i, selection) in selections.iter().enumerate() {
+            if *selection >= self.choices.len() {
+                return Err(DraftError::InvalidChoice(*selection));
+            }
+
+            if selections[..i].contains(selection) {
+                return Err(DraftError::DuplicateChoice(*selection));
+            }
+        }
+
+        let cards: Vec<u32> = selections
+            .iter()
+            .flat_map(|selection| self.choices[*selection].iter().copied())
+            .collect();
+
+        collection.add_change(Change::Add(ChangeContent::new(
+            cards.iter().map(|id| Card::new(*id)).collect(),
+            format!("{}", Utc::now().format(LAST_CHANGED_FORMAT)),
+            Some(self.round as u16),
+        )));
+
+        self.history.push(std::mem::take(&mut self.choices));
+        self.round += 1;
+        self.generate_choices();
+
+        return Ok(cards);
+    }
+
+    /// Lets `drafter` select the choices of the current round. See [`select`][`Self::select`].
+    ///
+    /// # Arguments
+    ///
+    /// * `drafter` – Strategy that selects the choices. The cards in `collection` are treated as
+    /// its picks so far.
+    /// * `collection` – Collection the selected cards are added to.
+    pub fn select_with(
+        &mut self,
+        drafter: &mut dyn Drafter,
+        collection: &mut Collection,
+    ) -> Result<Vec<u32>, DraftError> {
+        let picks: Vec<u32> = collection
+            .cards
+            .iter()
+            .flat_map(|(card, quantity)| vec![card.id; *quantity as usize])
+            .collect();
+        let selections = drafter.select(&self.choices, &picks, self.settings.selections_num);
+
+        return self.select(&selections, collection);
+    }
+
+    /// Reverts the selection of the previous round by undoing the most recent change of
+    /// `collection` and offering the same choices again. Returns [`DraftError::UnrelatedChange`]
+    /// if the most recent change is not the selection of the previous round.
+    ///
+    /// The choices that were generated for the discarded round are not put back into the card pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` – Collection the previous selection was added to.
+    pub fn undo(&mut self, collection: &mut Collection) -> Result<(), DraftError> {
+        if !self.settings.allow_undo {
+            return Err(DraftError::UndoNotAllowed);
+        }
+
+        if self.history.is_empty() {
+            return Err(DraftError::NothingToUndo);
+        }
+
+        match collection.changes.front() {
+            Some(Change::Add(content)) if content.round == Some((self.round - 1) as u16) => (),
+            _ => return Err(DraftError::UnrelatedChange),
+        }
+
+        let previous = self.history.pop().unwrap();
+        collection.undo_change();
+
+        self.round -= 1;
+        self.choices = previous;
+
+        return Ok(());
+    }
+
+    /// Returns the index of the generator used in the current round.
+    fn generator_index(&self) -> usize {
+        match self.settings.rotate {
+            SetRotation::Enabled(n) if n > 0 && !self.generators.is_empty() => {
+                (self.round / n) % self.generators.len()
+            }
+            _ => 0,
+        }
+    }
+
+    /// Replaces `self.choices` with the choices for the current round.
+    fn generate_choices(&mut self) {
+        if self.is_finished() || self.generators.is_empty() {
+            self.choices = Vec::new();
+            return;
+        }
+
+        let index = self.generator_index();
+        self.choices = self.generators[index]
+            .batch_generate(self.settings.choices_num, self.settings.cards_num);
+    }
+}
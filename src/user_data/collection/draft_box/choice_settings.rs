@@ -17,15 +17,26 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use serde::{Deserialize, Serialize};
 
+use crate::user_data::BanlistRef;
+
 use super::SetRotation;
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+/// Settings of a draft where the player selects from a number of card choices each round.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct ChoiceSettings {
+    /// Number of rounds in the draft.
     pub rounds_num: usize,
+    /// Number of choices offered each round.
     pub choices_num: usize,
+    /// Number of choices the player has to select each round.
     pub selections_num: usize,
+    /// Number of cards in each choice.
     pub cards_num: usize,
+    /// Groups of card set names the choices are generated from. See [`SetRotation`].
     pub sets: Vec<Vec<String>>,
     pub rotate: SetRotation,
+    /// Whether or not the selection of the previous round can be reverted.
     pub allow_undo: bool,
+    /// Banlist of the draft. Cards never show up more often than a deck may contain them.
+    pub banlist: BanlistRef,
 }
@@ -0,0 +1,88 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt;
+
+use crate::user_data::BanlistRef;
+
+/// Reasons why an input to a draft session was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DraftError {
+    /// The draft has no rounds left.
+    Finished,
+    /// The draft still has rounds left.
+    NotFinished,
+    /// The number of selected choices does not match the settings.
+    WrongSelectionCount { expected: usize, got: usize },
+    /// The selected index does not point to an available choice.
+    InvalidChoice(usize),
+    /// The same choice was selected more than once.
+    DuplicateChoice(usize),
+    /// There is no seat with the given index.
+    InvalidSeat(usize),
+    /// The seat with the given index already picked a card from its current pack.
+    AlreadyPicked(usize),
+    /// Undoing is disabled in the settings of the draft.
+    UndoNotAllowed,
+    /// There is no previous round that could be undone.
+    NothingToUndo,
+    /// The banlist of the draft settings can not be found.
+    UnknownBanlist(BanlistRef),
+    /// There is no card set with the given name.
+    UnknownSet(String),
+    /// The seat already picked as many copies of the card as the banlist of the draft allows.
+    LimitReached(u32),
+    /// The most recent change of the collection was not added by the previous round of the draft.
+    UnrelatedChange,
+}
+
+impl fmt::Display for DraftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DraftError::Finished => write!(f, "the draft is already finished"),
+            DraftError::NotFinished => write!(f, "the draft is not finished yet"),
+            DraftError::WrongSelectionCount { expected, got } => {
+                write!(f, "expected {} selections, got {}", expected, got)
+            }
+            DraftError::InvalidChoice(index) => write!(f, "there is no choice at index {}", index),
+            DraftError::DuplicateChoice(index) => {
+                write!(f, "the choice at index {} was selected twice", index)
+            }
+            DraftError::InvalidSeat(seat) => write!(f, "there is no seat {}", seat),
+            DraftError::AlreadyPicked(seat) => {
+                write!(f, "seat {} already picked from its current pack", seat)
+            }
+            DraftError::UndoNotAllowed => write!(f, "undoing is not allowed in this draft"),
+            DraftError::NothingToUndo => write!(f, "there is no round to undo"),
+            DraftError::UnknownBanlist(banlist) => {
+                write!(f, "the banlist {:?} can not be found", banlist)
+            }
+            DraftError::UnknownSet(set) => write!(f, "there is no card set named {}", set),
+            DraftError::LimitReached(card) => write!(
+                f,
+                "the banlist does not allow picking more copies of the card {}",
+                card
+            ),
+            DraftError::UnrelatedChange => write!(
+                f,
+                "the most recent change of the collection does not belong to the previous round"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DraftError {}
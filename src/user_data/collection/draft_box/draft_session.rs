@@ -0,0 +1,163 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{fmt, fs, io};
+
+use bincode::{
+    error::DecodeError, serde::decode_from_slice as decode, serde::encode_to_vec as encode,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::user_data::collection::BINCODE_CONFIG;
+use crate::utils::{Cache, PATHS};
+
+use super::{BattlePackSession, ChoiceDraftSession, DraftBox, DraftError, StandardDraftSession};
+
+/// Reasons why a saved draft session could not be resumed.
+#[derive(Debug)]
+pub enum DraftSessionError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file is not a valid draft session, e.g. because it was saved by an older version with a
+    /// different layout.
+    Decode(DecodeError),
+}
+
+impl fmt::Display for DraftSessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DraftSessionError::Io(error) => {
+                write!(f, "failed to read the draft session file: {}", error)
+            }
+            DraftSessionError::Decode(error) => {
+                write!(f, "failed to decode the draft session: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DraftSessionError {}
+
+impl From<io::Error> for DraftSessionError {
+    fn from(error: io::Error) -> Self {
+        DraftSessionError::Io(error)
+    }
+}
+
+impl From<DecodeError> for DraftSessionError {
+    fn from(error: DecodeError) -> Self {
+        DraftSessionError::Decode(error)
+    }
+}
+
+/// An in-progress draft of any kind that can be saved to and resumed from disk.
+///
+/// The saved state contains everything needed to continue the draft, including the remaining card
+/// pools and the state of their random number generators.
+#[derive(Serialize, Deserialize)]
+pub enum DraftSession {
+    BattlePack(BattlePackSession),
+    Choice(ChoiceDraftSession),
+    Standard(StandardDraftSession),
+}
+
+impl DraftSession {
+    /// Starts a new draft session based on the settings in `draft_box`. Returns `None` for
+    /// [`DraftBox::None`] and [`DraftError::UnknownBanlist`] or [`DraftError::UnknownSet`] if the
+    /// banlist or one of the sets of the settings can not be found.
+    ///
+    /// # Arguments
+    ///
+    /// * `draft_box` – Settings of the draft.
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn start(draft_box: &DraftBox, cache: &Cache) -> Result<Option<Self>, DraftError> {
+        let session = match draft_box {
+            DraftBox::None => return Ok(None),
+            DraftBox::BattlePackDraft(settings) => {
+                DraftSession::BattlePack(BattlePackSession::new(settings, cache)?)
+            }
+            DraftBox::ChoiceDraft(settings) => {
+                DraftSession::Choice(ChoiceDraftSession::new(settings, cache)?)
+            }
+            DraftBox::StandardDraft(settings) => {
+                DraftSession::Standard(StandardDraftSession::new(settings, cache)?)
+            }
+        };
+
+        return Ok(Some(session));
+    }
+
+    /// Whether or not the contained draft is finished.
+    pub fn is_finished(&self) -> bool {
+        match self {
+            DraftSession::BattlePack(session) => session.is_finished(),
+            DraftSession::Choice(session) => session.is_finished(),
+            DraftSession::Standard(session) => session.is_finished(),
+        }
+    }
+
+    /// Returns the names of all locally saved draft sessions.
+    pub fn get_names() -> Vec<String> {
+        if let Ok(read_dir) = PATHS.user_paths.drafts.read_dir() {
+            read_dir
+                .map(|path| {
+                    path.expect("Failed to read path.")
+                        .file_name()
+                        .into_string()
+                        .expect("Failed to get file name.")
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Resumes a draft session from its file name. Returns an error if the file can not be read or
+    /// decoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the draft session.
+    pub fn from_name(name: &String) -> Result<Self, DraftSessionError> {
+        let bytes = fs::read(&PATHS.user_paths.drafts.join(name))?;
+
+        return Ok(decode(&bytes, BINCODE_CONFIG)?.0);
+    }
+
+    /// Saves a draft session to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the draft session.
+    pub fn save(&self, name: &String) {
+        fs::write(
+            &PATHS.user_paths.drafts.join(name),
+            encode(self, BINCODE_CONFIG).unwrap(),
+        )
+        .expect("Failed to save draft session.");
+    }
+
+    /// Deletes the file of a saved draft session, e.g. after the draft is finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the draft session.
+    pub fn remove(name: &String) {
+        fs::remove_file(&PATHS.user_paths.drafts.join(name))
+            .expect("Failed to remove draft session.");
+    }
+}
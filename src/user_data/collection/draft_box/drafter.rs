@@ -0,0 +1,231 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::collections::HashMap;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::ext_data::cardinfo::CardinfoMetaType;
+use crate::utils::Cache;
+
+/// A pick strategy for seats that are not controlled by a human.
+///
+/// Implementors only have to [`rate`][`Drafter::rate`] cards. Picking from a pack and selecting
+/// from choices is derived from the ratings.
+pub trait Drafter {
+    /// Rates how much the drafter wants `card`. Higher is better.
+    ///
+    /// # Arguments
+    ///
+    /// * `card` – Id of the rated card.
+    /// * `picks` – Cards the drafter picked so far.
+    fn rate(&mut self, card: u32, picks: &[u32]) -> f64;
+
+    /// Returns the index of the card to pick from `pack`. Ties are broken in favour of the first
+    /// card.
+    ///
+    /// # Arguments
+    ///
+    /// * `pack` – Cards to pick from. Must not be empty.
+    /// * `picks` – Cards the drafter picked so far.
+    fn pick(&mut self, pack: &[u32], picks: &[u32]) -> usize {
+        let mut best = (0, f64::MIN);
+
+        for (i, card) in pack.iter().enumerate() {
+            let rating = self.rate(*card, picks);
+            if rating > best.1 {
+                best = (i, rating);
+            }
+        }
+
+        return best.0;
+    }
+
+    /// Returns the indices of the `n` choices with the highest summed rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `choices` – Choices to select from.
+    /// * `picks` – Cards the drafter picked so far.
+    /// * `n` – Number of choices to select.
+    fn select(&mut self, choices: &[Vec<u32>], picks: &[u32], n: usize) -> Vec<usize> {
+        let mut ratings: Vec<(usize, f64)> = choices
+            .iter()
+            .enumerate()
+            .map(|(i, choice)| (i, choice.iter().map(|card| self.rate(*card, picks)).sum()))
+            .collect();
+        ratings.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        return ratings.iter().take(n).map(|(i, _)| *i).collect();
+    }
+}
+
+/// Picks cards at random.
+pub struct RandomDrafter {
+    rng: ChaCha8Rng,
+}
+
+impl RandomDrafter {
+    /// Constructs a [`RandomDrafter`] with a randomly seeded random number generator.
+    pub fn new() -> Self {
+        Self {
+            rng: ChaCha8Rng::from_entropy(),
+        }
+    }
+
+    /// Constructs a [`RandomDrafter`] that always makes the same picks for the same `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the random number generator.
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for RandomDrafter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drafter for RandomDrafter {
+    fn rate(&mut self, _card: u32, _picks: &[u32]) -> f64 {
+        self.rng.gen()
+    }
+}
+
+/// Prefers cards of archetypes it already picked. Cards that belong to any archetype are preferred
+/// over generic ones.
+pub struct ArchetypeDrafter<'a> {
+    cardinfo: &'a CardinfoMetaType,
+}
+
+impl<'a> ArchetypeDrafter<'a> {
+    /// # Arguments
+    ///
+    /// * `cardinfo` – Card data used to look up archetypes.
+    pub fn new(cardinfo: &'a CardinfoMetaType) -> Self {
+        Self { cardinfo: cardinfo }
+    }
+}
+
+impl Drafter for ArchetypeDrafter<'_> {
+    fn rate(&mut self, card: u32, picks: &[u32]) -> f64 {
+        let archetype = match self.cardinfo.get(&card).and_then(|c| c.archetype.as_ref()) {
+            Some(archetype) => archetype,
+            None => return 0.0,
+        };
+
+        let shared = picks
+            .iter()
+            .filter(|pick| {
+                self.cardinfo
+                    .get(pick)
+                    .and_then(|c| c.archetype.as_ref())
+                    .map_or(false, |a| a == archetype)
+            })
+            .count();
+
+        return 1.0 + 2.0 * shared as f64;
+    }
+}
+
+/// Prefers cards that share an attribute or a type with the cards it already picked.
+pub struct CoherentDrafter<'a> {
+    cardinfo: &'a CardinfoMetaType,
+}
+
+impl<'a> CoherentDrafter<'a> {
+    /// # Arguments
+    ///
+    /// * `cardinfo` – Card data used to look up attributes and types.
+    pub fn new(cardinfo: &'a CardinfoMetaType) -> Self {
+        Self { cardinfo: cardinfo }
+    }
+}
+
+impl Drafter for CoherentDrafter<'_> {
+    fn rate(&mut self, card: u32, picks: &[u32]) -> f64 {
+        let card = match self.cardinfo.get(&card) {
+            Some(card) => card,
+            None => return 0.0,
+        };
+
+        let mut rating = 0.0;
+        for pick in picks.iter().filter_map(|pick| self.cardinfo.get(pick)) {
+            if card.attribute.is_some() && card.attribute == pick.attribute {
+                rating += 1.0;
+            }
+            if card.r#type == pick.r#type {
+                rating += 1.0;
+            }
+        }
+
+        return rating;
+    }
+}
+
+/// Prefers cards with a higher set rarity.
+pub struct RareDrafter {
+    /// Rank of the highest rarity each card is printed in.
+    ranks: HashMap<u32, u8>,
+}
+
+impl RareDrafter {
+    /// # Arguments
+    ///
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    /// * `sets` – Names of the sets whose rarities are considered.
+    pub fn new(cache: &Cache, sets: &[String]) -> Self {
+        let mut ranks: HashMap<u32, u8> = HashMap::new();
+
+        for printing in sets
+            .iter()
+            .filter_map(|set| cache.cardsets.get(set))
+            .flat_map(|set| set.printings.iter())
+        {
+            let rank = rarity_rank(&printing.rarity);
+            let entry = ranks.entry(printing.id).or_insert(rank);
+            *entry = (*entry).max(rank);
+        }
+
+        Self { ranks: ranks }
+    }
+}
+
+impl Drafter for RareDrafter {
+    fn rate(&mut self, card: u32, _picks: &[u32]) -> f64 {
+        *self.ranks.get(&card).unwrap_or(&0) as f64
+    }
+}
+
+/// Returns a rough rank of a set rarity, where `0` is a common.
+fn rarity_rank(rarity: &str) -> u8 {
+    match rarity {
+        "Common" | "Short Print" | "Super Short Print" => 0,
+        "Rare" => 1,
+        "Super Rare" | "Mosaic Rare" | "Starfoil Rare" | "Shatterfoil Rare" => 2,
+        "Ultra Rare" | "Ultimate Rare" | "Gold Rare" | "Premium Gold Rare" => 3,
+        "Secret Rare" | "Prismatic Secret Rare" | "Platinum Secret Rare" => 4,
+        "Ghost Rare" | "Starlight Rare" | "Collector's Rare" | "Quarter Century Secret Rare" => 5,
+        _ => 1,
+    }
+}
@@ -15,21 +15,39 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+mod battle_pack_draft;
 mod battle_pack_settings;
+mod choice_draft;
 mod choice_settings;
+mod draft_error;
+mod draft_session;
+mod drafter;
+mod standard_draft;
+mod standard_settings;
 
 use serde::{Deserialize, Serialize};
 
-pub use battle_pack_settings::BattlePackSettings;
+use crate::ext_data::banlists::Banlist;
+use crate::user_data::BanlistRef;
+use crate::utils::Cache;
+
+pub use battle_pack_draft::BattlePackSession;
+pub use battle_pack_settings::{BattlePackSettings, BATTLE_PACK_TAG};
+pub use choice_draft::ChoiceDraftSession;
 pub use choice_settings::ChoiceSettings;
+pub use draft_error::DraftError;
+pub use draft_session::{DraftSession, DraftSessionError};
+pub use drafter::{ArchetypeDrafter, CoherentDrafter, Drafter, RandomDrafter, RareDrafter};
+pub use standard_draft::{PassDirection, StandardDraftSession};
+pub use standard_settings::StandardSettings;
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub enum DraftBox {
     #[default]
     None,
     BattlePackDraft(BattlePackSettings),
     ChoiceDraft(ChoiceSettings),
-    // StandardDraft(StandardSettings),
+    StandardDraft(StandardSettings),
 }
 
 impl DraftBox {
@@ -39,6 +57,23 @@ impl DraftBox {
             _ => false,
         }
     }
+
+    pub fn is_standard_draft(&self) -> bool {
+        match self {
+            DraftBox::StandardDraft(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the banlist of the draft, or `None` for [`DraftBox::None`].
+    pub fn banlist(&self) -> Option<&BanlistRef> {
+        match self {
+            DraftBox::None => None,
+            DraftBox::BattlePackDraft(settings) => Some(&settings.banlist),
+            DraftBox::ChoiceDraft(settings) => Some(&settings.banlist),
+            DraftBox::StandardDraft(settings) => Some(&settings.banlist),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug)]
@@ -47,9 +82,44 @@ pub struct DraftBoxMeta {
     pub description: String,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+/// Returns the banlist a draft is restricted by. [`BanlistRef::None`] resolves to an empty
+/// banlist, a banlist that can not be found to [`DraftError::UnknownBanlist`].
+///
+/// # Arguments
+///
+/// * `banlist` – Banlist of the draft settings.
+/// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+fn resolve_banlist(banlist: &BanlistRef, cache: &Cache) -> Result<Banlist, DraftError> {
+    match banlist {
+        BanlistRef::None => Ok(Banlist::new()),
+        _ => banlist
+            .resolve(cache)
+            .ok_or_else(|| DraftError::UnknownBanlist(banlist.clone())),
+    }
+}
+
+/// Returns all card ids of `sets`, like [`Cache::get_cards_from_sets`], or
+/// [`DraftError::UnknownSet`] if one of the sets can not be found.
+///
+/// # Arguments
+///
+/// * `sets` – Card set names of the draft settings.
+/// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+fn cards_from_sets(sets: Vec<String>, cache: &Cache) -> Result<Vec<u32>, DraftError> {
+    if let Some(set) = sets.iter().find(|set| !cache.cardsets.contains_key(*set)) {
+        return Err(DraftError::UnknownSet(set.clone()));
+    }
+
+    return Ok(cache.get_cards_from_sets(sets));
+}
+
+/// Whether or not a draft moves on to the next group of sets after a number of rounds.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub enum SetRotation {
     #[default]
     Disabled,
     Enabled(usize),
 }
+
+#[cfg(test)]
+mod tests;
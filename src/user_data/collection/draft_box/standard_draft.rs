@@ -0,0 +1,364 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use chrono::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::banlists::{Banlist, LimitStatus};
+use crate::user_data::collection::{Card, Change, ChangeContent, Collection, LAST_CHANGED_FORMAT};
+use crate::utils::card_gen::CardGenerator;
+use crate::utils::Cache;
+
+use super::{cards_from_sets, resolve_banlist, DraftError, Drafter, StandardSettings};
+
+/// Direction packs are passed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PassDirection {
+    /// From seat `i` to seat `i + 1`.
+    Left,
+    /// From seat `i` to seat `i - 1`.
+    Right,
+}
+
+/// A running booster draft based on [`StandardSettings`].
+///
+/// Every seat opens a pack at the start of a pack round. Each seat picks one card from the pack in
+/// front of it and once all seats have picked, the packs are passed on. Packs are passed to the
+/// left in even pack rounds and to the right in odd ones. When all packs are empty the next pack
+/// round starts.
+///
+/// Forbidden cards are removed from the card pool. Limited and Semi-Limited cards can show up any
+/// number of times, but each seat can only pick as many copies as the banlist allows. If every card
+/// in front of a seat is over the limit, the picked card is still taken out of the pack, but it is
+/// not added to the picks of the seat.
+#[derive(Serialize, Deserialize)]
+pub struct StandardDraftSession {
+    settings: StandardSettings,
+    /// Seed all card generators are derived from.
+    seed: u64,
+    /// One generator per group of sets.
+    generators: Vec<CardGenerator>,
+    /// Banlist the picks of each seat are restricted by.
+    banlist: Banlist,
+    /// Current (zero based) pack round.
+    pack_round: usize,
+    /// Pack currently in front of each seat.
+    packs: Vec<Vec<u32>>,
+    /// Index of the card each seat picked in the current pick, if it already picked.
+    pending: Vec<Option<usize>>,
+    /// Cards picked by each seat.
+    picks: Vec<Vec<u32>>,
+    /// Number of picks of each seat that have already been committed to a collection.
+    committed: Vec<usize>,
+}
+
+impl StandardDraftSession {
+    /// Starts a new booster draft with a random seed and opens the packs of the first pack round.
+    /// Returns [`DraftError::UnknownBanlist`] or [`DraftError::UnknownSet`] if the banlist or one
+    /// of the sets of the settings can not be found.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` – Settings of the draft.
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn new(settings: &StandardSettings, cache: &Cache) -> Result<Self, DraftError> {
+        Self::with_seed(settings, cache, rand::random())
+    }
+
+    /// Starts a new booster draft that opens the same packs for the same `seed` and settings.
+    /// Same arguments as [`new`][`Self::new`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the draft. See [`seed_from_str`][`crate::utils::card_gen::seed_from_str`]
+    /// to use a string.
+    pub fn with_seed(
+        settings: &StandardSettings,
+        cache: &Cache,
+        seed: u64,
+    ) -> Result<Self, DraftError> {
+        let mut seeds = ChaCha8Rng::seed_from_u64(seed);
+        let banlist = resolve_banlist(&settings.banlist, cache)?;
+        let mut generators = Vec::new();
+        for sets in settings.sets.iter() {
+            let mut cards = cards_from_sets(sets.clone(), cache)?;
+            cards.retain(|card| !banlist.is_forbidden(*card));
+
+            generators.push(CardGenerator::from_seed_dedup(cards, 0, seeds.gen()));
+        }
+
+        let mut session = Self {
+            settings: settings.clone(),
+            seed: seed,
+            generators: generators,
+            banlist: banlist,
+            pack_round: 0,
+            packs: vec![Vec::new(); settings.seats_num],
+            pending: vec![None; settings.seats_num],
+            picks: vec![Vec::new(); settings.seats_num],
+            committed: vec![0; settings.seats_num],
+        };
+        session.open_packs();
+
+        return Ok(session);
+    }
+
+    /// Settings the draft was started with.
+    pub fn settings(&self) -> &StandardSettings {
+        &self.settings
+    }
+
+    /// Seed the draft was started with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The current (zero based) pack round.
+    pub fn pack_round(&self) -> usize {
+        self.pack_round
+    }
+
+    /// Direction the packs of the current pack round are passed in.
+    pub fn pass_direction(&self) -> PassDirection {
+        if self.pack_round % 2 == 0 {
+            PassDirection::Left
+        } else {
+            PassDirection::Right
+        }
+    }
+
+    /// Whether or not all packs have been drafted.
+    pub fn is_finished(&self) -> bool {
+        self.pack_round >= self.settings.packs_num
+    }
+
+    /// Pack currently in front of `seat`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` – Index of the seat.
+    pub fn pack(&self, seat: usize) -> Result<&Vec<u32>, DraftError> {
+        self.check_seat(seat)?;
+
+        return Ok(&self.packs[seat]);
+    }
+
+    /// All cards `seat` has picked so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` – Index of the seat.
+    pub fn picks(&self, seat: usize) -> Result<&Vec<u32>, DraftError> {
+        self.check_seat(seat)?;
+
+        return Ok(&self.picks[seat]);
+    }
+
+    /// Whether or not `seat` still has to pick a card before the packs are passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` – Index of the seat.
+    pub fn is_waiting_for(&self, seat: usize) -> bool {
+        seat < self.settings.seats_num
+            && self.pending[seat].is_none()
+            && !self.packs[seat].is_empty()
+    }
+
+    /// Seats that still have to pick a card before the packs are passed.
+    pub fn waiting_seats(&self) -> Vec<usize> {
+        (0..self.settings.seats_num)
+            .filter(|seat| self.is_waiting_for(*seat))
+            .collect()
+    }
+
+    /// Submits the pick of `seat`. Once all seats have picked, the picked cards are taken out of
+    /// the packs and the packs are passed on. Returns `true` if the packs were passed.
+    ///
+    /// Returns [`DraftError::LimitReached`] if `seat` already picked as many copies of the card as
+    /// the banlist allows, unless there is no other card in the pack it could pick.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` – Index of the picking seat.
+    /// * `index` – Index of the picked card in the pack in front of `seat`.
+    pub fn submit_pick(&mut self, seat: usize, index: usize) -> Result<bool, DraftError> {
+        if self.is_finished() {
+            return Err(DraftError::Finished);
+        }
+
+        self.check_seat(seat)?;
+
+        if self.pending[seat].is_some() {
+            return Err(DraftError::AlreadyPicked(seat));
+        }
+
+        if index >= self.packs[seat].len() {
+            return Err(DraftError::InvalidChoice(index));
+        }
+
+        let card = self.packs[seat][index];
+        if !self.can_pick(seat, card)
+            && self.packs[seat]
+                .iter()
+                .any(|other| self.can_pick(seat, *other))
+        {
+            return Err(DraftError::LimitReached(card));
+        }
+
+        self.pending[seat] = Some(index);
+
+        if self.waiting_seats().is_empty() {
+            self.pass_packs();
+            return Ok(true);
+        }
+
+        return Ok(false);
+    }
+
+    /// Lets `drafter` pick for `seat`. Used to fill seats that are not controlled by a human. The
+    /// drafter is only offered the cards `seat` can still pick. See
+    /// [`submit_pick`][`Self::submit_pick`].
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` – Index of the picking seat.
+    /// * `drafter` – Strategy that picks the card.
+    pub fn submit_bot_pick(
+        &mut self,
+        seat: usize,
+        drafter: &mut dyn Drafter,
+    ) -> Result<bool, DraftError> {
+        self.check_seat(seat)?;
+
+        let pack = &self.packs[seat];
+        let allowed: Vec<usize> = (0..pack.len())
+            .filter(|index| self.can_pick(seat, pack[*index]))
+            .collect();
+
+        let index = if allowed.is_empty() {
+            drafter.pick(pack, &self.picks[seat])
+        } else {
+            let cards: Vec<u32> = allowed.iter().map(|index| pack[*index]).collect();
+            allowed[drafter.pick(&cards, &self.picks[seat])]
+        };
+
+        return self.submit_pick(seat, index);
+    }
+
+    /// Adds all picks of `seat` that have not been committed yet to `collection`. Returns the
+    /// added cards.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` – Index of the seat.
+    /// * `collection` – Collection of the player at `seat`.
+    pub fn commit(
+        &mut self,
+        seat: usize,
+        collection: &mut Collection,
+    ) -> Result<Vec<u32>, DraftError> {
+        self.check_seat(seat)?;
+
+        let cards = self.picks[seat][self.committed[seat]..].to_vec();
+
+        if !cards.is_empty() {
+            collection.add_change(Change::Add(ChangeContent::new(
+                cards.iter().map(|id| Card::new(*id)).collect(),
+                format!("{}", Utc::now().format(LAST_CHANGED_FORMAT)),
+                None,
+            )));
+            self.committed[seat] = self.picks[seat].len();
+        }
+
+        return Ok(cards);
+    }
+
+    /// Returns an error if `seat` does not exist.
+    fn check_seat(&self, seat: usize) -> Result<(), DraftError> {
+        if seat < self.settings.seats_num {
+            Ok(())
+        } else {
+            Err(DraftError::InvalidSeat(seat))
+        }
+    }
+
+    /// Whether or not `seat` can pick another copy of `card`. Only Limited and Semi-Limited cards
+    /// are restricted.
+    fn can_pick(&self, seat: usize, card: u32) -> bool {
+        let status = self.banlist.status_of(card);
+        if status == LimitStatus::Unlimited {
+            return true;
+        }
+
+        let copies = self.picks[seat].iter().filter(|id| **id == card).count();
+
+        return copies < status.max_copies() as usize;
+    }
+
+    /// Moves the pending picks out of the packs and passes the packs on. Starts the next pack
+    /// round if all packs are empty.
+    fn pass_packs(&mut self) {
+        let seats_num = self.settings.seats_num;
+
+        for seat in 0..seats_num {
+            if let Some(index) = self.pending[seat].take() {
+                let card = self.packs[seat].remove(index);
+                if self.can_pick(seat, card) {
+                    self.picks[seat].push(card);
+                }
+            }
+        }
+
+        let direction = self.pass_direction();
+        let mut passed = vec![Vec::new(); seats_num];
+        for (seat, pack) in self.packs.drain(..).enumerate() {
+            let target = match direction {
+                PassDirection::Left => (seat + 1) % seats_num,
+                PassDirection::Right => (seat + seats_num - 1) % seats_num,
+            };
+            passed[target] = pack;
+        }
+        self.packs = passed;
+
+        if self.packs.iter().all(|pack| pack.is_empty()) {
+            self.pack_round += 1;
+            self.open_packs();
+        }
+    }
+
+    /// Opens a new pack for every seat, unless the draft is finished. Finishes the draft if no
+    /// cards are left to open.
+    fn open_packs(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+
+        if !self.generators.is_empty() {
+            let index = self.pack_round % self.generators.len();
+            self.packs = self.generators[index]
+                .batch_generate(self.settings.seats_num, self.settings.pack_size);
+        }
+
+        // No seat could pick from empty packs
+        if self.packs.iter().all(|pack| pack.is_empty()) {
+            self.pack_round = self.settings.packs_num;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::user_data::BanlistRef;
+
+/// Settings of a booster draft where a table of players picks cards from packs that are passed
+/// around.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StandardSettings {
+    /// Number of seats at the table.
+    pub seats_num: usize,
+    /// Number of packs each seat opens.
+    pub packs_num: usize,
+    /// Number of cards in each pack.
+    pub pack_size: usize,
+    /// Groups of card set names, one for each pack round. If there are fewer groups than packs,
+    /// the groups are repeated.
+    pub sets: Vec<Vec<String>>,
+    /// Banlist of the draft. Forbidden cards never show up and no seat picks more copies of a card
+    /// than a deck may contain.
+    pub banlist: BanlistRef,
+}
+
+impl Default for StandardSettings {
+    fn default() -> Self {
+        Self {
+            seats_num: 8,
+            packs_num: 3,
+            pack_size: 15,
+            sets: Vec::new(),
+            banlist: BanlistRef::None,
+        }
+    }
+}
@@ -0,0 +1,790 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+use bincode::{serde::decode_from_slice as decode, serde::encode_to_vec as encode};
+
+use crate::ext_data::banlists::{Banlist, BanlistSource, LimitStatus};
+use crate::ext_data::cardinfo::CardinfoMetaType;
+use crate::ext_data::cardsets::Printing;
+use crate::test_fixtures;
+use crate::user_data::collection::{
+    Action, ActionSchedule, ActionStep, BonusCard, BonusDraft, Card, Change, ChangeContent,
+    Collection, MainDraft, BINCODE_CONFIG,
+};
+use crate::user_data::BanlistRef;
+use crate::utils::Cache;
+
+fn init_cache() -> Cache {
+    let mut cache = Cache::default();
+
+    for (name, ids) in [("A", 1..=6), ("B", 7..=12)] {
+        let printings = ids
+            .map(|id| test_fixtures::printing(id, name, "Common"))
+            .collect();
+        test_fixtures::insert_set(&mut cache, name, &[], printings);
+    }
+
+    let mut printings: Vec<Printing> = (1..=6)
+        .map(|id| test_fixtures::printing(id, "BP", if id <= 4 { "Common" } else { "Rare" }))
+        .collect();
+    printings.extend((1..=6).map(|id| test_fixtures::printing(id, "BP", "Mosaic Rare")));
+    test_fixtures::insert_set(&mut cache, "BP", &[BATTLE_PACK_TAG], printings);
+
+    cache.rebuild_indexes();
+
+    cache
+}
+
+fn init_choice_settings(rotate: SetRotation) -> ChoiceSettings {
+    ChoiceSettings {
+        rounds_num: 2,
+        choices_num: 3,
+        selections_num: 1,
+        cards_num: 2,
+        sets: vec![vec!["A".to_string()], vec!["B".to_string()]],
+        rotate: rotate,
+        allow_undo: true,
+        banlist: BanlistRef::None,
+    }
+}
+
+#[test]
+fn test_choice_rounds() {
+    let mut collection = Collection::default();
+    let mut session =
+        ChoiceDraftSession::new(&init_choice_settings(SetRotation::Disabled), &init_cache())
+            .unwrap();
+
+    assert_eq!(session.choices().len(), 3);
+    assert!(session.choices().iter().all(|choice| choice.len() == 2));
+
+    let cards = session.select(&[1], &mut collection).unwrap();
+    assert_eq!(cards.len(), 2);
+    assert_eq!(session.round(), 1);
+
+    match collection.changes.front() {
+        Some(Change::Add(content)) => assert_eq!(content.round, Some(0)),
+        _ => panic!("Expected an added change."),
+    }
+
+    session.select(&[0], &mut collection).unwrap();
+    assert!(session.is_finished());
+    assert!(session.choices().is_empty());
+    assert_eq!(
+        collection
+            .cards
+            .values()
+            .map(|n| *n as usize)
+            .sum::<usize>(),
+        4
+    );
+    assert_eq!(
+        session.select(&[0], &mut collection),
+        Err(DraftError::Finished)
+    );
+}
+
+#[test]
+fn test_choice_selection_errors() {
+    let mut collection = Collection::default();
+    let mut session =
+        ChoiceDraftSession::new(&init_choice_settings(SetRotation::Disabled), &init_cache())
+            .unwrap();
+
+    assert_eq!(
+        session.select(&[0, 1], &mut collection),
+        Err(DraftError::WrongSelectionCount {
+            expected: 1,
+            got: 2
+        })
+    );
+    assert_eq!(
+        session.select(&[3], &mut collection),
+        Err(DraftError::InvalidChoice(3))
+    );
+    assert_eq!(
+        session.undo(&mut collection),
+        Err(DraftError::NothingToUndo)
+    );
+    assert!(collection.changes.is_empty());
+}
+
+#[test]
+fn test_choice_rotation_and_undo() {
+    let mut collection = Collection::default();
+    let mut session = ChoiceDraftSession::new(
+        &init_choice_settings(SetRotation::Enabled(1)),
+        &init_cache(),
+    )
+    .unwrap();
+
+    assert!(session.choices().concat().iter().all(|id| *id <= 6));
+    let first_choices = session.choices().clone();
+    session.select(&[2], &mut collection).unwrap();
+    assert!(session.choices().concat().iter().all(|id| *id >= 7));
+
+    session.undo(&mut collection).unwrap();
+    assert_eq!(session.round(), 0);
+    assert_eq!(session.choices(), &first_choices);
+    assert!(collection.changes.is_empty());
+    assert_eq!(collection.count_of(first_choices[2][0]), 0);
+
+    // Only the selection of the previous round can be undone
+    session.select(&[2], &mut Collection::default()).unwrap();
+    assert_eq!(
+        session.undo(&mut collection),
+        Err(DraftError::UnrelatedChange)
+    );
+
+    collection.add_change(Change::Add(ChangeContent::new(
+        vec![Card::new(1)],
+        String::new(),
+        None,
+    )));
+    assert_eq!(
+        session.undo(&mut collection),
+        Err(DraftError::UnrelatedChange)
+    );
+    assert_eq!(collection.count_of(1), 1);
+    assert_eq!(session.round(), 1);
+}
+
+#[test]
+fn test_battle_pack_slots() {
+    let cache = init_cache();
+    assert_eq!(
+        BattlePackSettings::available_sets(&cache),
+        vec!["BP".to_string()]
+    );
+
+    let settings = BattlePackSettings {
+        sets: vec!["BP".to_string()],
+        packs_num: 4,
+        ..Default::default()
+    };
+    let mut collection = Collection::default();
+    let mut session = BattlePackSession::new(&settings, &cache).unwrap();
+
+    let pack = session.open_pack(&mut collection).unwrap();
+    assert_eq!(pack.len(), 5);
+    assert!(pack[..3].iter().all(|id| *id <= 4));
+    assert!(pack[3] >= 5);
+
+    assert_eq!(session.open_all(&mut collection).len(), 3);
+    assert!(session.is_finished());
+    assert_eq!(collection.changes.len(), 4);
+    assert!(collection.count_of(pack[0]) >= 1);
+    assert!(collection.cards.keys().all(|card| card.printing.is_some()));
+    assert_eq!(
+        session.open_pack(&mut collection),
+        Err(DraftError::Finished)
+    );
+}
+
+#[test]
+fn test_standard_passing() {
+    let settings = StandardSettings {
+        seats_num: 3,
+        packs_num: 2,
+        pack_size: 2,
+        sets: vec![vec!["A".to_string()], vec!["B".to_string()]],
+        banlist: BanlistRef::None,
+    };
+    let mut session = StandardDraftSession::new(&settings, &init_cache()).unwrap();
+
+    let first_packs: Vec<Vec<u32>> = (0..3)
+        .map(|seat| session.pack(seat).unwrap().clone())
+        .collect();
+    assert!(first_packs.concat().iter().all(|id| *id <= 6));
+
+    assert_eq!(session.submit_pick(0, 0), Ok(false));
+    assert_eq!(session.submit_pick(0, 1), Err(DraftError::AlreadyPicked(0)));
+    assert_eq!(session.submit_pick(3, 0), Err(DraftError::InvalidSeat(3)));
+    assert_eq!(session.submit_pick(1, 2), Err(DraftError::InvalidChoice(2)));
+    assert_eq!(session.waiting_seats(), vec![1, 2]);
+    session.submit_pick(1, 0).unwrap();
+    assert_eq!(session.submit_pick(2, 0), Ok(true));
+
+    // Packs are passed to the left in the first pack round
+    assert_eq!(session.pack(1).unwrap(), &first_packs[0][1..].to_vec());
+    assert_eq!(session.picks(0).unwrap(), &vec![first_packs[0][0]]);
+
+    for seat in 0..3 {
+        session.submit_pick(seat, 0).unwrap();
+    }
+    assert_eq!(session.pack_round(), 1);
+    assert_eq!(session.pass_direction(), PassDirection::Right);
+    assert!(session.pack(0).unwrap().iter().all(|id| *id >= 7));
+
+    for _ in 0..2 {
+        for seat in 0..3 {
+            session.submit_pick(seat, 0).unwrap();
+        }
+    }
+    assert!(session.is_finished());
+
+    let mut collection = Collection::default();
+    assert_eq!(session.commit(0, &mut collection).unwrap().len(), 4);
+    assert!(session.commit(0, &mut collection).unwrap().is_empty());
+    assert_eq!(collection.changes.len(), 1);
+}
+
+#[test]
+fn test_standard_empty_pool() {
+    for sets in [Vec::new(), vec![Vec::new()]] {
+        let settings = StandardSettings {
+            seats_num: 2,
+            packs_num: 2,
+            pack_size: 3,
+            sets: sets,
+            banlist: BanlistRef::None,
+        };
+        let mut session = StandardDraftSession::new(&settings, &init_cache()).unwrap();
+
+        assert!(session.is_finished());
+        assert!(session.waiting_seats().is_empty());
+        assert_eq!(session.submit_pick(0, 0), Err(DraftError::Finished));
+    }
+}
+
+#[test]
+fn test_bot_drafters() {
+    let cache = init_cache();
+    let settings = StandardSettings {
+        seats_num: 2,
+        packs_num: 1,
+        pack_size: 3,
+        sets: vec![vec!["BP".to_string()]],
+        banlist: BanlistRef::None,
+    };
+    let mut session = StandardDraftSession::new(&settings, &cache).unwrap();
+    let mut random = RandomDrafter::new();
+    let mut rare = RareDrafter::new(&cache, &["BP".to_string()]);
+
+    while !session.is_finished() {
+        session.submit_bot_pick(0, &mut random).unwrap();
+        session.submit_bot_pick(1, &mut rare).unwrap();
+    }
+    assert_eq!(session.picks(0).unwrap().len(), 3);
+    assert_eq!(session.picks(1).unwrap().len(), 3);
+
+    // Every card in "BP" has a foil printing, so the rare drafter rates them all the same
+    assert_eq!(rare.pick(&[1, 5], &[]), 0);
+    assert_eq!(rare.select(&[vec![1], vec![2, 3]], &[], 1), vec![1]);
+}
+
+#[test]
+fn test_rare_drafter() {
+    let mut cache = init_cache();
+    let printings = [
+        (20, "Common"),
+        (21, "Rare"),
+        (22, "Secret Rare"),
+        (23, "Ultra Rare"),
+    ]
+    .iter()
+    .map(|(id, rarity)| test_fixtures::printing(*id, "R", rarity))
+    .collect();
+    test_fixtures::insert_set(&mut cache, "R", &[], printings);
+
+    let mut rare = RareDrafter::new(&cache, &["R".to_string()]);
+    assert_eq!(rare.pick(&[20, 21, 22, 23], &[]), 2);
+    assert_eq!(rare.pick(&[99, 20, 21], &[]), 2);
+    assert_eq!(
+        rare.select(&[vec![20, 20, 20], vec![21], vec![23]], &[], 2),
+        vec![2, 1]
+    );
+}
+
+/// Card data with two Blue-Eyes monsters and three other monsters.
+fn init_cardinfo() -> CardinfoMetaType {
+    let cardinfo = r#"{"data": [
+        {"id": 1, "name": "Blue-Eyes White Dragon", "type": "Normal Monster", "desc": "",
+         "race": "Dragon", "attribute": "LIGHT", "archetype": "Blue-Eyes"},
+        {"id": 2, "name": "Blue-Eyes Alternative White Dragon", "type": "Effect Monster",
+         "desc": "", "race": "Dragon", "attribute": "LIGHT", "archetype": "Blue-Eyes"},
+        {"id": 3, "name": "Dark Magician", "type": "Normal Monster", "desc": "",
+         "race": "Spellcaster", "attribute": "DARK", "archetype": "Dark Magician"},
+        {"id": 4, "name": "Kuriboh", "type": "Effect Monster", "desc": "", "race": "Fiend",
+         "attribute": "DARK"},
+        {"id": 5, "name": "Gem-Knight Garnet", "type": "Normal Monster", "desc": "",
+         "race": "Pyro", "attribute": "EARTH", "archetype": "Gem-Knight"}
+    ]}"#;
+
+    test_fixtures::parse_cardinfo(cardinfo)
+}
+
+#[test]
+fn test_archetype_drafter() {
+    let cardinfo = init_cardinfo();
+    let mut drafter = ArchetypeDrafter::new(&cardinfo);
+
+    // Cards of an archetype are preferred over generic cards
+    assert_eq!(drafter.pick(&[4, 3], &[]), 1);
+    // Cards of an already picked archetype are preferred over other archetypes
+    assert_eq!(drafter.pick(&[4, 3, 2], &[1]), 2);
+    assert_eq!(drafter.pick(&[5, 2, 3], &[3, 3]), 2);
+    assert_eq!(
+        drafter.select(&[vec![5], vec![4, 4], vec![2]], &[1], 1),
+        vec![2]
+    );
+}
+
+#[test]
+fn test_coherent_drafter() {
+    let cardinfo = init_cardinfo();
+    let mut drafter = CoherentDrafter::new(&cardinfo);
+
+    // Kuriboh shares the attribute with Dark Magician
+    assert_eq!(drafter.pick(&[1, 5, 4], &[3]), 2);
+    // Blue-Eyes Alternative White Dragon shares the attribute and the type
+    assert_eq!(drafter.pick(&[3, 5, 2], &[1]), 2);
+    // Unknown cards are rated lowest
+    assert_eq!(drafter.pick(&[99, 4], &[3]), 1);
+}
+
+#[test]
+fn test_session_roundtrip() {
+    let mut settings = init_choice_settings(SetRotation::Disabled);
+    settings.rounds_num = 3;
+    let mut session = ChoiceDraftSession::new(&settings, &init_cache()).unwrap();
+    session.select(&[0], &mut Collection::default()).unwrap();
+
+    let encoded = encode(&DraftSession::Choice(session), BINCODE_CONFIG).unwrap();
+    let mut original = match decode(&encoded, BINCODE_CONFIG).unwrap().0 {
+        DraftSession::Choice(session) => session,
+        _ => panic!("Expected a choice draft."),
+    };
+    let mut restored = match decode(&encoded, BINCODE_CONFIG).unwrap().0 {
+        DraftSession::Choice(session) => session,
+        _ => panic!("Expected a choice draft."),
+    };
+
+    assert_eq!(restored.round(), 1);
+    assert_eq!(restored.choices(), original.choices());
+
+    // Both sessions continue with the same random number generator state
+    original.select(&[1], &mut Collection::default()).unwrap();
+    restored.select(&[1], &mut Collection::default()).unwrap();
+    assert_eq!(restored.choices(), original.choices());
+}
+
+#[test]
+fn test_seeded_sessions() {
+    let cache = init_cache();
+    let settings = StandardSettings {
+        seats_num: 2,
+        packs_num: 2,
+        pack_size: 3,
+        sets: vec![vec!["A".to_string(), "B".to_string()]],
+        banlist: BanlistRef::None,
+    };
+    let session_a = StandardDraftSession::with_seed(&settings, &cache, 7).unwrap();
+    let session_b = StandardDraftSession::with_seed(&settings, &cache, 7).unwrap();
+
+    assert_eq!(session_a.seed(), 7);
+    assert_eq!(session_a.pack(0), session_b.pack(0));
+    assert_eq!(session_a.pack(1), session_b.pack(1));
+
+    let settings = BattlePackSettings {
+        sets: vec!["BP".to_string()],
+        ..Default::default()
+    };
+    let mut session_a = BattlePackSession::with_seed(&settings, &cache, 7).unwrap();
+    let mut session_b = BattlePackSession::with_seed(&settings, &cache, 7).unwrap();
+    assert_eq!(
+        session_a.open_all(&mut Collection::default()),
+        session_b.open_all(&mut Collection::default())
+    );
+}
+
+#[test]
+fn test_action_schedule() {
+    let cache = init_cache();
+    let mut collection = Collection::default();
+    let battle_pack = DraftBox::BattlePackDraft(BattlePackSettings {
+        sets: vec!["BP".to_string()],
+        packs_num: 1,
+        ..Default::default()
+    });
+
+    collection.schedule = ActionSchedule::new(vec![
+        Action::BonusDraft(BonusDraft {
+            name: "Early bonus".to_string(),
+            main_draft_id: Some(1),
+            settings: battle_pack.clone(),
+        }),
+        Action::MainDraft(MainDraft {
+            name: "Week 1".to_string(),
+            id: 1,
+            settings: battle_pack.clone(),
+        }),
+        Action::BonusDraft(BonusDraft {
+            name: "Bonus".to_string(),
+            main_draft_id: Some(1),
+            settings: battle_pack,
+        }),
+        Action::BonusCard(BonusCard {
+            name: "Reward".to_string(),
+            cards: vec![42],
+        }),
+    ]);
+
+    assert!(matches!(
+        collection.advance_schedule(&cache).unwrap(),
+        ActionStep::Skipped { .. }
+    ));
+
+    for _ in 0..2 {
+        assert!(matches!(
+            collection.advance_schedule(&cache).unwrap(),
+            ActionStep::Draft { .. }
+        ));
+        assert_eq!(collection.finish_draft(), Err(DraftError::NotFinished));
+        collection.play_draft(|session, collection| match session {
+            DraftSession::BattlePack(session) => session.open_all(collection),
+            _ => panic!("Expected a Battle Pack draft."),
+        });
+        assert_eq!(collection.finish_draft(), Ok(()));
+    }
+
+    assert!(matches!(
+        collection.advance_schedule(&cache).unwrap(),
+        ActionStep::BonusCards { .. }
+    ));
+    assert!(collection.schedule.is_finished());
+    assert!(matches!(
+        collection.advance_schedule(&cache).unwrap(),
+        ActionStep::Finished
+    ));
+    assert_eq!(collection.changes.len(), 3);
+    assert_eq!(collection.cards[&Card::new(42)], 1);
+}
+
+#[test]
+fn test_action_schedule_skipped_main_draft() {
+    let cache = init_cache();
+    let mut collection = Collection::default();
+
+    collection.schedule = ActionSchedule::new(vec![
+        Action::MainDraft(MainDraft {
+            name: "Week 1".to_string(),
+            id: 1,
+            settings: DraftBox::None,
+        }),
+        Action::BonusDraft(BonusDraft {
+            name: "Bonus".to_string(),
+            main_draft_id: Some(1),
+            settings: DraftBox::BattlePackDraft(BattlePackSettings {
+                sets: vec!["BP".to_string()],
+                packs_num: 1,
+                ..Default::default()
+            }),
+        }),
+    ]);
+
+    for _ in 0..2 {
+        assert!(matches!(
+            collection.advance_schedule(&cache).unwrap(),
+            ActionStep::Skipped { .. }
+        ));
+    }
+    assert!(!collection.schedule.main_draft_completed(1));
+    assert!(collection.schedule.is_finished());
+}
+
+#[test]
+fn test_action_schedule_resume() {
+    let cache = init_cache();
+    let mut collection = Collection::default();
+
+    collection.schedule = ActionSchedule::new(vec![
+        Action::MainDraft(MainDraft {
+            name: "Week 1".to_string(),
+            id: 1,
+            settings: DraftBox::BattlePackDraft(BattlePackSettings {
+                sets: vec!["BP".to_string()],
+                packs_num: 2,
+                ..Default::default()
+            }),
+        }),
+        Action::BonusCard(BonusCard {
+            name: "Reward".to_string(),
+            cards: vec![42],
+        }),
+    ]);
+
+    assert!(matches!(
+        collection.advance_schedule(&cache).unwrap(),
+        ActionStep::Draft { .. }
+    ));
+    collection.play_draft(|session, collection| match session {
+        DraftSession::BattlePack(session) => session.open_pack(collection).unwrap(),
+        _ => panic!("Expected a Battle Pack draft."),
+    });
+
+    let bytes = encode(&collection, BINCODE_CONFIG).unwrap();
+    let mut collection: Collection = decode(&bytes, BINCODE_CONFIG).unwrap().0;
+
+    assert!(!collection.schedule.main_draft_completed(1));
+    assert!(matches!(
+        collection.advance_schedule(&cache).unwrap(),
+        ActionStep::Draft { .. }
+    ));
+    collection.play_draft(|session, collection| match session {
+        DraftSession::BattlePack(session) => session.open_pack(collection).unwrap(),
+        _ => panic!("Expected a Battle Pack draft."),
+    });
+    assert_eq!(collection.finish_draft(), Ok(()));
+    assert!(collection.schedule.main_draft_completed(1));
+    assert_eq!(collection.changes.len(), 2);
+    assert!(matches!(
+        collection.advance_schedule(&cache).unwrap(),
+        ActionStep::BonusCards { .. }
+    ));
+}
+
+#[test]
+fn test_draft_banlist() {
+    let mut cache = init_cache();
+    let date = chrono::NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+    cache
+        .banlists
+        .entry(BanlistSource::Tcg)
+        .or_default()
+        .insert(
+            date,
+            Banlist::from_iter([(1, LimitStatus::Forbidden), (5, LimitStatus::Forbidden)]),
+        );
+
+    let settings = StandardSettings {
+        seats_num: 8,
+        packs_num: 2,
+        pack_size: 5,
+        sets: vec![vec!["A".to_string()]],
+        banlist: BanlistRef::Official(BanlistSource::Tcg, date),
+    };
+    let session = StandardDraftSession::new(&settings, &cache).unwrap();
+    for seat in 0..8 {
+        assert!(session
+            .pack(seat)
+            .unwrap()
+            .iter()
+            .all(|id| *id != 1 && *id != 5));
+    }
+
+    let settings = BattlePackSettings {
+        sets: vec!["BP".to_string()],
+        banlist: BanlistRef::Official(BanlistSource::Tcg, date),
+        ..Default::default()
+    };
+    let mut session = BattlePackSession::new(&settings, &cache).unwrap();
+    let packs = session.open_all(&mut Collection::default());
+    assert!(packs.concat().iter().all(|id| *id != 1 && *id != 5));
+
+    cache
+        .banlists
+        .entry(BanlistSource::Tcg)
+        .or_default()
+        .insert(
+            date,
+            Banlist::from_iter((1..=6).map(|id| (id, LimitStatus::Limited))),
+        );
+    let mut settings = init_choice_settings(SetRotation::Disabled);
+    settings.rounds_num = 6;
+    settings.choices_num = 1;
+    settings.cards_num = 2;
+    settings.sets = vec![vec!["A".to_string()]];
+    settings.banlist = BanlistRef::Official(BanlistSource::Tcg, date);
+
+    let mut collection = Collection::default();
+    let mut session = ChoiceDraftSession::new(&settings, &cache).unwrap();
+    while !session.is_finished() {
+        session.select(&[0], &mut collection).unwrap();
+    }
+    assert_eq!(collection.cards.len(), 6);
+    assert!(collection.cards.values().all(|quantity| *quantity == 1));
+}
+
+#[test]
+fn test_unknown_banlist() {
+    let cache = init_cache();
+    let banlist = BanlistRef::Custom("__test_missing_banlist".to_string());
+    let error = DraftError::UnknownBanlist(banlist.clone());
+
+    let settings = StandardSettings {
+        sets: vec![vec!["A".to_string()]],
+        banlist: banlist.clone(),
+        ..Default::default()
+    };
+    assert_eq!(
+        StandardDraftSession::new(&settings, &cache).err(),
+        Some(error.clone())
+    );
+
+    let settings = BattlePackSettings {
+        sets: vec!["BP".to_string()],
+        banlist: banlist.clone(),
+        ..Default::default()
+    };
+    assert_eq!(
+        BattlePackSession::new(&settings, &cache).err(),
+        Some(error.clone())
+    );
+
+    let mut choice_settings = init_choice_settings(SetRotation::Disabled);
+    choice_settings.banlist = banlist;
+    assert_eq!(
+        ChoiceDraftSession::new(&choice_settings, &cache).err(),
+        Some(error.clone())
+    );
+
+    let mut collection = Collection::default();
+    collection.schedule = ActionSchedule::new(vec![Action::MainDraft(MainDraft {
+        name: "Week 1".to_string(),
+        id: 1,
+        settings: DraftBox::BattlePackDraft(settings),
+    })]);
+    assert_eq!(collection.advance_schedule(&cache).err(), Some(error));
+    assert_eq!(collection.schedule.position(), 0);
+    assert!(collection.schedule.pending_draft().is_none());
+}
+
+#[test]
+fn test_unknown_set() {
+    let cache = init_cache();
+    let error = DraftError::UnknownSet("C".to_string());
+
+    let mut settings = init_choice_settings(SetRotation::Enabled(1));
+    settings.sets[1].push("C".to_string());
+    assert_eq!(
+        ChoiceDraftSession::new(&settings, &cache).err(),
+        Some(error.clone())
+    );
+
+    let standard_settings = StandardSettings {
+        sets: vec![
+            vec!["A".to_string()],
+            vec!["B".to_string(), "C".to_string()],
+        ],
+        ..Default::default()
+    };
+    assert_eq!(
+        StandardDraftSession::new(&standard_settings, &cache).err(),
+        Some(error.clone())
+    );
+
+    let mut collection = Collection::default();
+    collection.schedule = ActionSchedule::new(vec![Action::MainDraft(MainDraft {
+        name: "Week 1".to_string(),
+        id: 1,
+        settings: DraftBox::ChoiceDraft(settings),
+    })]);
+    assert_eq!(collection.advance_schedule(&cache).err(), Some(error));
+    assert!(collection.schedule.pending_draft().is_none());
+}
+
+#[test]
+fn test_draft_limits() {
+    let mut cache = init_cache();
+    let date = chrono::NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+    cache
+        .banlists
+        .entry(BanlistSource::Tcg)
+        .or_default()
+        .insert(
+            date,
+            Banlist::from_iter([(1, LimitStatus::Limited), (2, LimitStatus::SemiLimited)]),
+        );
+    let banlist = BanlistRef::Official(BanlistSource::Tcg, date);
+    let count = |cards: &[u32], id: u32| cards.iter().filter(|card| **card == id).count();
+
+    // Limited and Semi-Limited cards are restricted per seat, not for the whole table
+    let settings = StandardSettings {
+        sets: vec![vec!["A".to_string()]],
+        banlist: banlist.clone(),
+        ..Default::default()
+    };
+    let mut session = StandardDraftSession::with_seed(&settings, &cache, 7).unwrap();
+    let mut drafter = RandomDrafter::new();
+    let mut opened_rounds = 0;
+    let mut limit_reached = false;
+    while !session.is_finished() {
+        if session.waiting_seats().len() == settings.seats_num
+            && session.pack(0).unwrap().len() == settings.pack_size
+        {
+            opened_rounds += 1;
+            for seat in 0..settings.seats_num {
+                assert_eq!(session.pack(seat).unwrap().len(), settings.pack_size);
+            }
+        }
+
+        let pack = session.pack(0).unwrap().clone();
+        if session.picks(0).unwrap().contains(&1) && pack.iter().any(|card| *card > 2) {
+            if let Some(index) = pack.iter().position(|card| *card == 1) {
+                limit_reached = true;
+                assert_eq!(
+                    session.submit_pick(0, index),
+                    Err(DraftError::LimitReached(1))
+                );
+            }
+        }
+
+        for seat in session.waiting_seats() {
+            session.submit_bot_pick(seat, &mut drafter).unwrap();
+        }
+    }
+    assert_eq!(opened_rounds, settings.packs_num);
+    assert!(limit_reached);
+
+    let mut picks = Vec::new();
+    for seat in 0..settings.seats_num {
+        let seat_picks = session.picks(seat).unwrap();
+        assert_eq!(count(seat_picks, 1), 1);
+        assert_eq!(count(seat_picks, 2), 2);
+        picks.extend(seat_picks.iter().copied());
+    }
+    assert!(count(&picks, 3) > 3);
+
+    let settings = BattlePackSettings {
+        sets: vec!["BP".to_string()],
+        packs_num: 20,
+        banlist: banlist.clone(),
+        ..Default::default()
+    };
+    let mut session = BattlePackSession::with_seed(&settings, &cache, 7).unwrap();
+    let cards = session.open_all(&mut Collection::default()).concat();
+    assert_eq!(count(&cards, 1), 1);
+    assert_eq!(count(&cards, 2), 2);
+
+    let mut settings = init_choice_settings(SetRotation::Disabled);
+    settings.rounds_num = 8;
+    settings.choices_num = 1;
+    settings.sets = vec![vec!["A".to_string()]];
+    settings.banlist = banlist;
+
+    let mut collection = Collection::default();
+    let mut session = ChoiceDraftSession::with_seed(&settings, &cache, 7).unwrap();
+    while !session.is_finished() {
+        session.select(&[0], &mut collection).unwrap();
+    }
+    assert_eq!(collection.count_of(1), 1);
+    assert_eq!(collection.count_of(2), 2);
+    assert_eq!(collection.count_of(3), 3);
+}
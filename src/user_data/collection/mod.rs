@@ -15,39 +15,94 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+mod action;
 mod card;
 mod change;
 mod collection_builder;
 mod draft_box;
 mod meta_data;
+mod sealed_pool;
+mod sealed_pool_builder;
 
+pub use action::{
+    Action, ActionSchedule, ActionStep, BonusCard, BonusDraft, MainDraft, PendingDraft,
+};
 pub use card::Card;
-pub use change::Change;
+pub use change::{Change, ChangeContent};
 pub use collection_builder::CollectionBuilder;
-pub use draft_box::{DraftBox, DraftBoxMeta, SetRotation};
+pub use draft_box::{
+    ArchetypeDrafter, BattlePackSession, BattlePackSettings, ChoiceDraftSession, ChoiceSettings,
+    CoherentDrafter, DraftBox, DraftBoxMeta, DraftError, DraftSession, DraftSessionError, Drafter,
+    PassDirection, RandomDrafter, RareDrafter, SetRotation, StandardDraftSession, StandardSettings,
+    BATTLE_PACK_TAG,
+};
 pub use meta_data::MetaData;
+pub use sealed_pool::{OpenedPack, SealedPool, SealedPoolError};
+pub use sealed_pool_builder::{Product, SealedPoolBuilder};
 
 use std::collections::{HashMap, VecDeque};
+use std::fmt;
 use std::fs;
+use std::io;
+use std::path::Path;
 
 use bincode::{
     config::{BigEndian, Configuration, Fixint},
+    error::DecodeError,
     serde::decode_from_slice as decode,
     serde::encode_to_vec as encode,
 };
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::utils::PATHS;
+use crate::user_data::BanlistRef;
+use crate::utils::{Cache, PATHS};
 
-/// Bincode configuration for all collections.
-static BINCODE_CONFIG: Configuration<BigEndian, Fixint> = bincode::config::standard()
+/// Bincode configuration for all collections, draft sessions and custom banlists.
+pub(super) static BINCODE_CONFIG: Configuration<BigEndian, Fixint> = bincode::config::standard()
     .with_big_endian()
     .with_fixed_int_encoding()
     .write_fixed_array_length();
 
 pub static LAST_CHANGED_FORMAT: &str = "%Y%m%d_%H%M%S";
 
+/// Reasons why a saved collection could not be loaded.
+#[derive(Debug)]
+pub enum CollectionError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file is not a valid collection, e.g. because it was saved by an older version with a
+    /// different layout.
+    Decode(DecodeError),
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectionError::Io(error) => {
+                write!(f, "failed to read the collection file: {}", error)
+            }
+            CollectionError::Decode(error) => {
+                write!(f, "failed to decode the collection: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CollectionError {}
+
+impl From<io::Error> for CollectionError {
+    fn from(error: io::Error) -> Self {
+        CollectionError::Io(error)
+    }
+}
+
+impl From<DecodeError> for CollectionError {
+    fn from(error: DecodeError) -> Self {
+        CollectionError::Decode(error)
+    }
+}
+
 #[derive(Serialize, Deserialize, Default, Debug)]
 pub struct Collection {
     pub meta_data: MetaData,
@@ -55,6 +110,9 @@ pub struct Collection {
     pub changes: VecDeque<Change>,
     pub draft_boxes: Vec<(DraftBoxMeta, DraftBox)>,
     pub tags: HashMap<String, Vec<Card>>,
+    pub schedule: ActionSchedule,
+    /// Banlist that decks built from this collection are validated against.
+    pub banlist: BanlistRef,
 }
 
 impl Collection {
@@ -79,19 +137,25 @@ impl Collection {
         }
     }
 
-    /// Gets a collection from its file name.
+    /// Gets a collection from its file name. Returns an error if the file can not be read or was
+    /// saved in a layout that is not compatible with this version.
     ///
     /// # Arguments
     ///
     /// * `name` – Name of the collection.
-    pub fn from_name(name: &String) -> Self {
-        decode(
-            &fs::read(&PATHS.user_paths.collections.join(name))
-                .expect("Failed to read collection."),
-            BINCODE_CONFIG,
-        )
-        .expect("Failed to decode collection.")
-        .0
+    pub fn from_name(name: &String) -> Result<Self, CollectionError> {
+        return Self::from_path(&PATHS.user_paths.collections.join(name));
+    }
+
+    /// Gets a collection from a file, like [`from_name`][`Collection::from_name`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` – Path of the collection file.
+    fn from_path(path: &Path) -> Result<Self, CollectionError> {
+        let bytes = fs::read(path)?;
+
+        return Ok(decode(&bytes, BINCODE_CONFIG)?.0);
     }
 
     /// Saves a collection to a file.
@@ -123,6 +187,18 @@ impl Collection {
         .0
     }
 
+    /// Returns how many copies of a card the collection contains, counting all printings.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    pub fn count_of(&self, id: u32) -> u8 {
+        self.cards
+            .iter()
+            .filter(|(card, _)| card.id == id)
+            .fold(0, |count, (_, quantity)| count.saturating_add(*quantity))
+    }
+
     /// Adds a new `Change` and applies it to [`cards`][`Collection::cards`].
     pub fn add_change(&mut self, change: Change) {
         match &change {
@@ -146,6 +222,129 @@ impl Collection {
         }
     }
 
+    /// Executes the next action of [`schedule`][`Collection::schedule`].
+    ///
+    /// Bonus cards are added to the collection right away. Drafts are started and kept on the
+    /// schedule, so that they are saved with the collection. As long as a draft has not been
+    /// finished with [`finish_draft`][`Collection::finish_draft`], it is returned again instead
+    /// of executing the next action. If a draft can not be started, the error is returned and
+    /// the schedule does not move on.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn advance_schedule(&mut self, cache: &Cache) -> Result<ActionStep, DraftError> {
+        if let Some(pending) = self.schedule.pending_draft() {
+            return Ok(ActionStep::Draft {
+                name: pending.name.clone(),
+            });
+        }
+
+        let action = match self.schedule.next_action() {
+            Some(action) => action.clone(),
+            None => return Ok(ActionStep::Finished),
+        };
+
+        let step = match action {
+            Action::None => ActionStep::Skipped {
+                name: String::new(),
+            },
+            Action::MainDraft(main_draft) => self.start_draft(
+                main_draft.name,
+                Some(main_draft.id),
+                &main_draft.settings,
+                cache,
+            )?,
+            Action::BonusDraft(bonus_draft) => {
+                let main_draft_completed = bonus_draft
+                    .main_draft_id
+                    .map_or(true, |id| self.schedule.main_draft_completed(id));
+
+                if main_draft_completed {
+                    self.start_draft(bonus_draft.name, None, &bonus_draft.settings, cache)?
+                } else {
+                    ActionStep::Skipped {
+                        name: bonus_draft.name,
+                    }
+                }
+            }
+            Action::BonusCard(bonus_card) => {
+                self.add_change(Change::Add(ChangeContent::new(
+                    bonus_card.cards.iter().map(|id| Card::new(*id)).collect(),
+                    format!("{}", Utc::now().format(LAST_CHANGED_FORMAT)),
+                    None,
+                )));
+
+                ActionStep::BonusCards {
+                    name: bonus_card.name,
+                    cards: bonus_card.cards,
+                }
+            }
+        };
+        self.schedule.advance();
+
+        return Ok(step);
+    }
+
+    /// Plays the pending draft of [`schedule`][`Collection::schedule`]. Returns `None` if no
+    /// draft is pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `play` – Called with the draft session and this collection, so that drafted cards can
+    ///   be added to it.
+    pub fn play_draft<T>(
+        &mut self,
+        play: impl FnOnce(&mut DraftSession, &mut Collection) -> T,
+    ) -> Option<T> {
+        let mut pending = self.schedule.pending.take()?;
+        let result = play(&mut pending.session, self);
+        self.schedule.pending = Some(pending);
+
+        return Some(result);
+    }
+
+    /// Finishes the pending draft of [`schedule`][`Collection::schedule`] after its cards have
+    /// been added to this collection. From then on a finished main draft unlocks its bonus
+    /// drafts. Does nothing if no draft is pending.
+    ///
+    /// Returns [`DraftError::NotFinished`] and keeps the draft if its session is not finished.
+    pub fn finish_draft(&mut self) -> Result<(), DraftError> {
+        if let Some(pending) = self.schedule.pending_draft() {
+            if !pending.session.is_finished() {
+                return Err(DraftError::NotFinished);
+            }
+        }
+
+        self.schedule.finish_pending();
+
+        return Ok(());
+    }
+
+    /// Starts a draft of the schedule and keeps it as the pending draft.
+    fn start_draft(
+        &mut self,
+        name: String,
+        main_draft_id: Option<u8>,
+        settings: &DraftBox,
+        cache: &Cache,
+    ) -> Result<ActionStep, DraftError> {
+        let step = match DraftSession::start(settings, cache)? {
+            Some(session) => {
+                self.schedule.pending = Some(PendingDraft {
+                    name: name.clone(),
+                    main_draft_id: main_draft_id,
+                    session: session,
+                });
+
+                ActionStep::Draft { name: name }
+            }
+            None => ActionStep::Skipped { name: name },
+        };
+
+        return Ok(step);
+    }
+
     /// # Arguments
     ///
     /// * `cards` – Reference to the cards to add.
@@ -159,18 +358,36 @@ impl Collection {
         }
     }
 
+    /// Removes one copy of each card. Cards without a printing are removed from the copies without
+    /// a printing first and otherwise from the printing with the lowest set code.
+    ///
     /// # Arguments
     ///
     /// * `cards` – Reference to the cards to remove.
     fn remove_cards(&mut self, cards: &Vec<Card>) {
         for card in cards.iter() {
-            if let Some(quantity) = self.cards.get(card) {
-                if quantity < &1 {
-                    self.cards.remove(card);
-                } else {
-                    self.cards.insert(card.clone(), quantity - 1);
+            let key = if card.printing.is_none() && !self.cards.contains_key(card) {
+                self.cards
+                    .keys()
+                    .filter(|owned| owned.id == card.id)
+                    .min_by(|a, b| a.printing.cmp(&b.printing))
+                    .cloned()
+            } else {
+                Some(card.clone())
+            };
+
+            if let Some(key) = key {
+                if let Some(quantity) = self.cards.get(&key) {
+                    if quantity <= &1 {
+                        self.cards.remove(&key);
+                    } else {
+                        self.cards.insert(key, quantity - 1);
+                    }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
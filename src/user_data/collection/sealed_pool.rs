@@ -0,0 +1,128 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt;
+
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::cardsets::Printing;
+
+use super::{Card, Change, ChangeContent, Collection, SealedPoolBuilder, LAST_CHANGED_FORMAT};
+
+/// Reasons why a [`SealedPool`] could not be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SealedPoolError {
+    /// There is no card set with the given name.
+    UnknownSet(String),
+}
+
+impl fmt::Display for SealedPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SealedPoolError::UnknownSet(set) => write!(f, "there is no card set named {}", set),
+        }
+    }
+}
+
+impl std::error::Error for SealedPoolError {}
+
+/// A single opened pack of a [`SealedPool`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OpenedPack {
+    /// Name of the card set the pack belongs to.
+    pub set: String,
+    pub cards: Vec<Printing>,
+}
+
+/// Cards of a number of opened products. Constructed with [`SealedPool::builder`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use ygod_core::user_data::{Collection, SealedPool};
+/// use ygod_core::utils::{http, CACHE};
+///
+/// http::load_local_data();
+///
+/// let pool = SealedPool::builder()
+///     .product("Battle Pack: Epic Dawn", 3)
+///     .product("Spell Ruler", 2)
+///     .build(&CACHE.lock().unwrap())
+///     .unwrap();
+///
+/// let mut collection = Collection::default();
+/// pool.commit(&mut collection);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SealedPool {
+    packs: Vec<OpenedPack>,
+    seed: u64,
+}
+
+impl SealedPool {
+    /// Constructs a [`SealedPoolBuilder`].
+    pub fn builder() -> SealedPoolBuilder {
+        SealedPoolBuilder::new()
+    }
+
+    pub(super) fn new(packs: Vec<OpenedPack>, seed: u64) -> Self {
+        Self {
+            packs: packs,
+            seed: seed,
+        }
+    }
+
+    /// All opened packs in the order they were opened.
+    pub fn packs(&self) -> &Vec<OpenedPack> {
+        &self.packs
+    }
+
+    /// Seed the packs were generated with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Ids of all cards in the pool.
+    pub fn cards(&self) -> Vec<u32> {
+        self.packs
+            .iter()
+            .flat_map(|pack| pack.cards.iter().map(|printing| printing.id))
+            .collect()
+    }
+
+    /// Returns a [`Change::Add`] containing all cards of the pool.
+    pub fn to_change(&self) -> Change {
+        Change::Add(ChangeContent::new(
+            self.packs
+                .iter()
+                .flat_map(|pack| pack.cards.iter().map(Card::from))
+                .collect(),
+            format!("{}", Utc::now().format(LAST_CHANGED_FORMAT)),
+            None,
+        ))
+    }
+
+    /// Adds all cards of the pool to `collection` as a single change.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` – Collection the cards are added to.
+    pub fn commit(&self, collection: &mut Collection) {
+        collection.add_change(self.to_change());
+    }
+}
@@ -0,0 +1,179 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::ext_data::banlists::{Banlist, LimitStatus};
+use crate::utils::card_gen::{PackGenerator, PackLayout};
+use crate::utils::Cache;
+
+use super::sealed_pool::{OpenedPack, SealedPool, SealedPoolError};
+use super::{BattlePackSettings, BATTLE_PACK_TAG};
+
+/// A product that is opened for a [`SealedPool`].
+#[derive(Clone, Debug)]
+pub struct Product {
+    /// Name of the card set.
+    pub set: String,
+    /// Number of packs that are opened.
+    pub packs: usize,
+    /// Layout of the packs. If `None`, the layout is chosen based on the tags of the set.
+    pub layout: Option<PackLayout>,
+}
+
+/// Builder for a [`SealedPool`]. Constructed with [`SealedPool::builder`].
+pub struct SealedPoolBuilder {
+    products: Vec<Product>,
+    seed: Option<u64>,
+    banlist: Banlist,
+}
+
+impl Default for SealedPoolBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SealedPoolBuilder {
+    /// Constructs a new `SealedPoolBuilder` without any products.
+    pub fn new() -> Self {
+        Self {
+            products: Vec::new(),
+            seed: None,
+            banlist: Banlist::new(),
+        }
+    }
+
+    /// Adds `packs` packs of `set` to the pool.
+    ///
+    /// Sets tagged with [`BATTLE_PACK_TAG`] use the layout of
+    /// [`BattlePackSettings::default`], all other sets use [`PackLayout::booster`].
+    ///
+    /// # Arguments
+    ///
+    /// * `set` – Name of the card set.
+    /// * `packs` – Number of packs to open.
+    pub fn product(self, set: &str, packs: usize) -> Self {
+        self.add_product(Product {
+            set: set.to_string(),
+            packs: packs,
+            layout: None,
+        })
+    }
+
+    /// Adds `packs` packs of `set` with a custom `layout` to the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` – Name of the card set.
+    /// * `packs` – Number of packs to open.
+    /// * `layout` – Layout of the packs.
+    pub fn product_with_layout(self, set: &str, packs: usize, layout: PackLayout) -> Self {
+        self.add_product(Product {
+            set: set.to_string(),
+            packs: packs,
+            layout: Some(layout),
+        })
+    }
+
+    /// Adds a [`Product`] to the pool, e.g. one that was stored in the settings of an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` – Product to open.
+    pub fn add_product(mut self, product: Product) -> Self {
+        self.products.push(product);
+        self
+    }
+
+    /// Sets the seed the packs are generated with. A random seed is used if this is not called.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the pool. See [`seed_from_str`][`crate::utils::card_gen::seed_from_str`]
+    /// to use a string.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the banlist of the pool. Forbidden cards are never opened, limited and semi-limited
+    /// cards at most once or twice across all products.
+    ///
+    /// # Arguments
+    ///
+    /// * `banlist` – Banlist of the pool, e.g. the result of
+    /// [`BanlistRef::resolve`][`crate::user_data::BanlistRef::resolve`].
+    pub fn banlist(mut self, banlist: Banlist) -> Self {
+        self.banlist = banlist;
+        self
+    }
+
+    /// Opens all packs and returns the resulting [`SealedPool`]. Packs are opened in the order the
+    /// products were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn build(self, cache: &Cache) -> Result<SealedPool, SealedPoolError> {
+        let seed = self.seed.unwrap_or_else(rand::random);
+        let mut seeds = ChaCha8Rng::seed_from_u64(seed);
+        let mut packs = Vec::new();
+        let mut banlist = self.banlist;
+
+        for product in self.products {
+            let set = cache
+                .cardsets
+                .get(&product.set)
+                .ok_or_else(|| SealedPoolError::UnknownSet(product.set.clone()))?;
+
+            let layout = match product.layout {
+                Some(layout) => layout,
+                None if set.tags.iter().any(|tag| tag == BATTLE_PACK_TAG) => {
+                    BattlePackSettings::default().layout()
+                }
+                None => PackLayout::booster(),
+            };
+
+            let mut generator = PackGenerator::from_seed_banlist(
+                set.printings.clone(),
+                layout,
+                &banlist,
+                seeds.gen(),
+            );
+            for cards in generator.batch_generate(product.packs) {
+                // Opened copies count towards the limits of the following products
+                for printing in cards.iter() {
+                    let status = match banlist.status_of(printing.id) {
+                        LimitStatus::SemiLimited => LimitStatus::Limited,
+                        LimitStatus::Limited => LimitStatus::Forbidden,
+                        _ => continue,
+                    };
+                    banlist.set_status(printing.id, status);
+                }
+
+                packs.push(OpenedPack {
+                    set: product.set.clone(),
+                    cards: cards,
+                });
+            }
+        }
+
+        return Ok(SealedPool::new(packs, seed));
+    }
+}
@@ -0,0 +1,253 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::change::ChangeContent;
+use super::*;
+
+use crate::ext_data::banlists::{Banlist, LimitStatus};
+use crate::test_fixtures;
+use crate::utils::card_gen::{PackLayout, RaritySlot};
+
+#[test]
+fn test_remove_cards() {
+    let mut collection = Collection::default();
+    let cards = vec![Card::new(1), Card::new(1), Card::new(2)];
+
+    collection.add_change(Change::Add(ChangeContent::new(
+        cards.clone(),
+        String::new(),
+        None,
+    )));
+    assert_eq!(collection.cards[&Card::new(1)], 2);
+
+    collection.add_change(Change::Remove(ChangeContent::new(
+        vec![Card::new(1), Card::new(2)],
+        String::new(),
+        None,
+    )));
+    assert_eq!(collection.cards[&Card::new(1)], 1);
+    // Removing the last copy removes the card
+    assert!(!collection.cards.contains_key(&Card::new(2)));
+
+    collection.undo_change();
+    collection.undo_change();
+    assert!(collection.cards.is_empty());
+}
+
+#[test]
+fn test_remove_printings() {
+    let printing = |code: &str| Card {
+        id: 1,
+        printing: Some(code.to_string()),
+    };
+    let mut collection = Collection::default();
+    collection.cards.insert(Card::new(1), 1);
+    collection.cards.insert(printing("SDK-001"), 1);
+    collection.cards.insert(printing("LOB-001"), 2);
+    assert_eq!(collection.count_of(1), 4);
+
+    // Copies without a printing are removed first, then the lowest set code
+    collection.add_change(Change::Remove(ChangeContent::new(
+        vec![Card::new(1), Card::new(1), Card::new(1)],
+        String::new(),
+        None,
+    )));
+    assert_eq!(collection.count_of(1), 1);
+    assert_eq!(collection.cards.get(&printing("SDK-001")), Some(&1));
+
+    // Cards with a printing are only removed from that printing
+    collection.add_change(Change::Remove(ChangeContent::new(
+        vec![printing("LOB-001")],
+        String::new(),
+        None,
+    )));
+    assert_eq!(collection.count_of(1), 1);
+
+    collection.add_change(Change::Remove(ChangeContent::new(
+        vec![Card::new(1), Card::new(2)],
+        String::new(),
+        None,
+    )));
+    assert!(collection.cards.is_empty());
+}
+
+/// Cache with a booster set "Booster" and a Battle Pack set "BP".
+fn init_cache() -> Cache {
+    let mut cache = Cache::default();
+
+    for (name, tags, ids) in [
+        ("Booster", &[][..], 1..=12),
+        ("BP", &[BATTLE_PACK_TAG][..], 101..=112),
+    ] {
+        let printings = ids
+            .map(|id| {
+                let rarity = match id % 100 {
+                    1..=8 => "Common",
+                    9..=10 => "Rare",
+                    _ => "Super Rare",
+                };
+                test_fixtures::printing(id, name, rarity)
+            })
+            .collect();
+        test_fixtures::insert_set(&mut cache, name, tags, printings);
+    }
+
+    return cache;
+}
+
+#[test]
+fn test_sealed_pool_packs() {
+    let cache = init_cache();
+    let pool = SealedPool::builder()
+        .product("Booster", 3)
+        .product("BP", 2)
+        .product_with_layout(
+            "Booster",
+            1,
+            PackLayout {
+                slots: vec![RaritySlot::fixed(2, &["Rare"])],
+            },
+        )
+        .seed(5)
+        .build(&cache)
+        .unwrap();
+
+    assert_eq!(pool.seed(), 5);
+    assert_eq!(pool.packs().len(), 6);
+    assert!(pool.packs()[..3]
+        .iter()
+        .all(|pack| pack.set == "Booster" && pack.cards.len() == PackLayout::booster().size()));
+    assert!(pool.packs()[3..5].iter().all(|pack| pack.set == "BP"
+        && pack.cards.len() == BattlePackSettings::default().layout().size()));
+    assert!(pool.packs()[5]
+        .cards
+        .iter()
+        .all(|printing| printing.rarity == "Rare"));
+    assert_eq!(
+        pool.cards().len(),
+        pool.packs()
+            .iter()
+            .map(|pack| pack.cards.len())
+            .sum::<usize>()
+    );
+
+    assert_eq!(
+        SealedPool::builder()
+            .product("Unknown", 1)
+            .build(&cache)
+            .unwrap_err(),
+        SealedPoolError::UnknownSet("Unknown".to_string())
+    );
+}
+
+#[test]
+fn test_sealed_pool_seed() {
+    let cache = init_cache();
+    let build = |seed| {
+        SealedPool::builder()
+            .add_product(Product {
+                set: "Booster".to_string(),
+                packs: 4,
+                layout: None,
+            })
+            .seed(seed)
+            .build(&cache)
+            .unwrap()
+    };
+
+    assert_eq!(build(1).packs(), build(1).packs());
+    assert_ne!(build(1).packs(), build(2).packs());
+}
+
+#[test]
+fn test_sealed_pool_commit() {
+    let cache = init_cache();
+    let pool = SealedPool::builder()
+        .product("Booster", 2)
+        .seed(3)
+        .build(&cache)
+        .unwrap();
+
+    match pool.to_change() {
+        Change::Add(content) => {
+            assert_eq!(content.cards.len(), pool.cards().len());
+            assert_eq!(content.cards[0].id, pool.packs()[0].cards[0].id);
+            assert_eq!(
+                content.cards[0].printing.as_ref(),
+                Some(&pool.packs()[0].cards[0].code)
+            );
+        }
+        _ => panic!("expected an added change"),
+    }
+
+    let mut collection = Collection::default();
+    pool.commit(&mut collection);
+    assert_eq!(collection.changes.len(), 1);
+
+    let mut ids = pool.cards();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(
+        ids.iter()
+            .map(|id| collection.count_of(*id) as usize)
+            .sum::<usize>(),
+        pool.cards().len()
+    );
+}
+
+#[test]
+fn test_sealed_pool_banlist() {
+    let cache = init_cache();
+    let pool = SealedPool::builder()
+        .product("Booster", 12)
+        .product("Booster", 12)
+        .banlist(Banlist::from_iter([
+            (1, LimitStatus::Limited),
+            (2, LimitStatus::SemiLimited),
+            (3, LimitStatus::Forbidden),
+        ]))
+        .seed(7)
+        .build(&cache)
+        .unwrap();
+    let cards = pool.cards();
+    let count = |id: u32| cards.iter().filter(|card| **card == id).count();
+
+    assert_eq!(count(1), 1);
+    assert_eq!(count(2), 2);
+    assert_eq!(count(3), 0);
+    assert!(count(4) > 2);
+}
+
+#[test]
+fn test_from_path_old_layout() {
+    let path = std::env::temp_dir().join("ygod_core_test_old_layout");
+    let old_collection = (
+        MetaData::default(),
+        HashMap::<u32, u8>::from([(1, 2)]),
+        VecDeque::<Change>::new(),
+    );
+    fs::write(&path, encode(&old_collection, BINCODE_CONFIG).unwrap()).unwrap();
+
+    let result = Collection::from_path(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert!(matches!(result, Err(CollectionError::Decode(_))));
+    assert!(matches!(
+        Collection::from_path(&path),
+        Err(CollectionError::Io(_))
+    ));
+}
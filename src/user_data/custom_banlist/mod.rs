@@ -0,0 +1,213 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::path::Path;
+use std::{fmt, fs, io};
+
+use bincode::{
+    error::DecodeError, serde::decode_from_slice as decode, serde::encode_to_vec as encode,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::banlists::{banlist_at, Banlist, BanlistSource, BanlistsMetaType};
+use crate::user_data::collection::BINCODE_CONFIG;
+use crate::utils::{Cache, PATHS};
+
+/// Reasons why a saved custom banlist could not be loaded.
+#[derive(Debug)]
+pub enum CustomBanlistError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file is not a valid custom banlist, e.g. because it was saved by an older version with
+    /// a different layout.
+    Decode(DecodeError),
+}
+
+impl fmt::Display for CustomBanlistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomBanlistError::Io(error) => {
+                write!(f, "failed to read the custom banlist file: {}", error)
+            }
+            CustomBanlistError::Decode(error) => {
+                write!(f, "failed to decode the custom banlist: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CustomBanlistError {}
+
+impl From<io::Error> for CustomBanlistError {
+    fn from(error: io::Error) -> Self {
+        CustomBanlistError::Io(error)
+    }
+}
+
+impl From<DecodeError> for CustomBanlistError {
+    fn from(error: DecodeError) -> Self {
+        CustomBanlistError::Decode(error)
+    }
+}
+
+/// A banlist created by the user, e.g. for a sealed league. Cards can be edited via
+/// [`banlist`][`CustomBanlist::banlist`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct CustomBanlist {
+    pub name: String,
+    pub description: String,
+    /// Date of the official banlist this banlist was derived from.
+    pub based_on: Option<NaiveDate>,
+    pub banlist: Banlist,
+}
+
+impl CustomBanlist {
+    /// Constructs an empty [`CustomBanlist`] on which every card is unlimited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the banlist.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Constructs a [`CustomBanlist`] that starts out as a copy of the official banlist in effect
+    /// on `date`. Returns `None` if `date` is before the first banlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the banlist.
+    /// * `banlists` – Official banlists of one region or format, usually from
+    /// [`Cache::banlists`][`crate::utils::Cache::banlists`].
+    /// * `date` – Date the official banlist was in effect.
+    pub fn derive_from(name: &str, banlists: &BanlistsMetaType, date: NaiveDate) -> Option<Self> {
+        let (based_on, banlist) = banlist_at(banlists, date)?;
+
+        Some(Self {
+            name: name.to_string(),
+            description: String::new(),
+            based_on: Some(*based_on),
+            banlist: banlist.clone(),
+        })
+    }
+
+    /// Returns the names of all locally saved custom banlists.
+    pub fn get_names() -> Vec<String> {
+        if let Ok(read_dir) = PATHS.user_paths.banlists.read_dir() {
+            read_dir
+                .map(|path| {
+                    path.expect("Failed to read path.")
+                        .file_name()
+                        .into_string()
+                        .expect("Failed to get file name.")
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Gets a custom banlist from its file name. Returns an error if the file can not be read or
+    /// decoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – File name of the banlist.
+    pub fn from_name(name: &String) -> Result<Self, CustomBanlistError> {
+        return Self::from_path(&PATHS.user_paths.banlists.join(name));
+    }
+
+    /// Saves a custom banlist to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – File name of the banlist.
+    pub fn save(&self, name: &String) {
+        self.save_to(&PATHS.user_paths.banlists.join(name));
+    }
+
+    /// Deletes the file of a saved custom banlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – File name of the banlist.
+    pub fn remove(name: &String) {
+        fs::remove_file(&PATHS.user_paths.banlists.join(name))
+            .expect("Failed to remove custom banlist.");
+    }
+
+    /// Gets a custom banlist from a file, like [`from_name`][`CustomBanlist::from_name`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` – Path of the banlist file.
+    fn from_path(path: &Path) -> Result<Self, CustomBanlistError> {
+        let bytes = fs::read(path)?;
+
+        return Ok(decode(&bytes, BINCODE_CONFIG)?.0);
+    }
+
+    /// Saves a custom banlist to a file, like [`save`][`CustomBanlist::save`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` – Path of the banlist file.
+    fn save_to(&self, path: &Path) {
+        fs::write(path, encode(self, BINCODE_CONFIG).unwrap())
+            .expect("Failed to save custom banlist.");
+    }
+}
+
+/// Banlist that applies to a [`Collection`][`crate::user_data::Collection`] or a
+/// [`DraftBox`][`crate::user_data::DraftBox`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub enum BanlistRef {
+    /// Only the 3-copy rule applies.
+    #[default]
+    None,
+    /// The official banlist of a region or format that was in effect on the date.
+    Official(BanlistSource, NaiveDate),
+    /// The locally saved [`CustomBanlist`] with the file name.
+    Custom(String),
+}
+
+impl BanlistRef {
+    /// Returns the referenced banlist. Returns `None` for [`BanlistRef::None`] and if the banlist
+    /// can not be found.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    pub fn resolve(&self, cache: &Cache) -> Option<Banlist> {
+        match self {
+            BanlistRef::None => None,
+            BanlistRef::Official(source, date) => cache
+                .banlist_at(source, *date)
+                .map(|(_, banlist)| banlist.clone()),
+            BanlistRef::Custom(name) => CustomBanlist::from_name(name)
+                .ok()
+                .map(|custom| custom.banlist),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+use crate::ext_data::banlists::LimitStatus;
+
+#[test]
+fn test_derive_from() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let mut cache = Cache::default();
+    cache
+        .banlists
+        .entry(BanlistSource::Tcg)
+        .or_default()
+        .insert(
+            date(2023, 2, 1),
+            Banlist::from_iter([(1, LimitStatus::Forbidden), (2, LimitStatus::Limited)]),
+        );
+
+    assert_eq!(
+        CustomBanlist::derive_from(
+            "League",
+            &cache.banlists[&BanlistSource::Tcg],
+            date(2022, 1, 1)
+        ),
+        None
+    );
+
+    let mut custom = CustomBanlist::derive_from(
+        "League",
+        &cache.banlists[&BanlistSource::Tcg],
+        date(2023, 3, 1),
+    )
+    .unwrap();
+    assert_eq!(custom.based_on, Some(date(2023, 2, 1)));
+    assert_eq!(custom.banlist.status_of(2), LimitStatus::Limited);
+
+    // Editing the custom banlist leaves the official one untouched
+    custom.banlist.set_status(3, LimitStatus::Forbidden);
+    assert_eq!(
+        BanlistRef::Official(BanlistSource::Tcg, date(2023, 2, 1))
+            .resolve(&cache)
+            .unwrap()
+            .status_of(3),
+        LimitStatus::Unlimited
+    );
+    assert!(custom.banlist.is_forbidden(3));
+
+    assert_eq!(BanlistRef::None.resolve(&cache), None);
+    assert_eq!(
+        BanlistRef::Official(BanlistSource::Tcg, date(2000, 1, 1)).resolve(&cache),
+        None
+    );
+}
+
+#[test]
+fn test_save_and_load() {
+    let path = std::env::temp_dir().join("ygod_core_test_custom_banlist");
+    let mut custom = CustomBanlist::new("League");
+    custom.description = String::from("Sealed league");
+    custom.banlist.set_status(1, LimitStatus::Forbidden);
+    custom.banlist.set_status(2, LimitStatus::SemiLimited);
+
+    custom.save_to(&path);
+    let loaded = CustomBanlist::from_path(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.unwrap(), custom);
+    assert!(matches!(
+        CustomBanlist::from_path(&path),
+        Err(CustomBanlistError::Io(_))
+    ));
+}
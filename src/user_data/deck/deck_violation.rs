@@ -0,0 +1,91 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt;
+
+use crate::ext_data::banlists::LimitStatus;
+
+use super::DeckSection;
+
+/// A single rule of a [`Deck`][`super::Deck`] that is broken, as returned by
+/// [`Deck::validate`][`super::Deck::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckViolation {
+    /// The number of cards in a section is outside of its allowed range.
+    SectionSize {
+        section: DeckSection,
+        count: usize,
+        min: usize,
+        max: usize,
+    },
+    /// The card is placed in a section it can not be played from, e.g. a Fusion Monster in the
+    /// main deck.
+    WrongSection { id: u32, section: DeckSection },
+    /// The card is not contained in the card data.
+    UnknownCard(u32),
+    /// The deck contains more copies of the card than the 3-copy rule allows.
+    TooManyCopies { id: u32, count: usize },
+    /// The deck contains more copies of the card than its status on the banlist allows.
+    Banlisted {
+        id: u32,
+        count: usize,
+        status: LimitStatus,
+    },
+    /// The collection does not contain enough copies of the card.
+    NotOwned { id: u32, count: usize, owned: u8 },
+}
+
+impl fmt::Display for DeckViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckViolation::SectionSize {
+                section,
+                count,
+                min,
+                max,
+            } => write!(
+                f,
+                "the {} deck contains {} cards, but must contain {} to {}",
+                section, count, min, max
+            ),
+            DeckViolation::WrongSection { id, section } => {
+                write!(f, "card {} can not be played from the {} deck", id, section)
+            }
+            DeckViolation::UnknownCard(id) => write!(f, "card {} is unknown", id),
+            DeckViolation::TooManyCopies { id, count } => {
+                write!(
+                    f,
+                    "card {} is contained {} times, but at most 3 copies are allowed",
+                    id, count
+                )
+            }
+            DeckViolation::Banlisted { id, count, status } => write!(
+                f,
+                "card {} is contained {} times, but it is {} and allows {}",
+                id,
+                count,
+                status,
+                status.max_copies()
+            ),
+            DeckViolation::NotOwned { id, count, owned } => write!(
+                f,
+                "card {} is contained {} times, but only {} are owned",
+                id, count, owned
+            ),
+        }
+    }
+}
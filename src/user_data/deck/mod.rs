@@ -0,0 +1,247 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+mod deck_violation;
+mod ydk;
+
+pub use deck_violation::DeckViolation;
+pub use ydk::{YdkError, YdkImport};
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::banlists::{Banlist, LimitStatus};
+use crate::ext_data::cardinfo::CardinfoMetaType;
+use crate::user_data::Collection;
+use crate::utils::Cache;
+
+/// Minimum and maximum number of cards in the main deck.
+pub const MAIN_DECK_SIZE: (usize, usize) = (40, 60);
+/// Minimum and maximum number of cards in the extra deck.
+pub const EXTRA_DECK_SIZE: (usize, usize) = (0, 15);
+/// Minimum and maximum number of cards in the side deck.
+pub const SIDE_DECK_SIZE: (usize, usize) = (0, 15);
+/// Number of copies of a card a deck may contain if the card is not on the banlist.
+pub const MAX_COPIES: usize = 3;
+
+/// One of the sections of a [`Deck`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeckSection {
+    Main,
+    Extra,
+    Side,
+}
+
+impl fmt::Display for DeckSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckSection::Main => write!(f, "main"),
+            DeckSection::Extra => write!(f, "extra"),
+            DeckSection::Side => write!(f, "side"),
+        }
+    }
+}
+
+/// A deck built from the cards of a [`Collection`]. Cards are stored by their id.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct Deck {
+    pub main: Vec<u32>,
+    pub extra: Vec<u32>,
+    pub side: Vec<u32>,
+}
+
+impl Deck {
+    /// Constructs a [`Deck`] and sorts `cards` into the main and extra deck.
+    ///
+    /// # Arguments
+    ///
+    /// * `cards` – Ids of the cards in the deck.
+    /// * `cardinfo` – Card data used to look up card types.
+    pub fn from_cards(cards: &[u32], cardinfo: &CardinfoMetaType) -> Self {
+        let mut deck = Self::default();
+
+        for card in cards.iter() {
+            deck.add(*card, cardinfo);
+        }
+
+        return deck;
+    }
+
+    /// Adds a card to the extra deck if it is an extra deck monster, otherwise to the main deck.
+    /// Unknown cards are added to the main deck.
+    ///
+    /// # Arguments
+    ///
+    /// * `card` – Id of the added card.
+    /// * `cardinfo` – Card data used to look up the card type.
+    pub fn add(&mut self, card: u32, cardinfo: &CardinfoMetaType) {
+        match section_of(card, cardinfo) {
+            Some(DeckSection::Extra) => self.extra.push(card),
+            _ => self.main.push(card),
+        }
+    }
+
+    /// Returns the cards of `section`.
+    pub fn section(&self, section: DeckSection) -> &Vec<u32> {
+        match section {
+            DeckSection::Main => &self.main,
+            DeckSection::Extra => &self.extra,
+            DeckSection::Side => &self.side,
+        }
+    }
+
+    /// Returns how many copies of each card the deck contains, counting all sections.
+    pub fn counts(&self) -> BTreeMap<u32, usize> {
+        let mut counts = BTreeMap::new();
+
+        for card in self.main.iter().chain(&self.extra).chain(&self.side) {
+            *counts.entry(*card).or_insert(0) += 1;
+        }
+
+        return counts;
+    }
+
+    /// Checks the deck against the deck building rules and returns every broken rule. The deck is
+    /// legal if the result is empty.
+    ///
+    /// The following rules are checked:
+    ///
+    /// * The main deck contains 40 to 60 cards, the extra and side deck at most 15 cards each.
+    /// * Extra deck monsters are only in the extra deck and other cards are not.
+    /// * The deck contains at most 3 copies of a card, and not more than `banlist` allows.
+    /// * `collection` owns every copy of each card.
+    ///
+    /// # Arguments
+    ///
+    /// * `cardinfo` – Card data used to look up card types.
+    /// * `banlist` – Banlist to check against, e.g. from
+    /// [`Cache::banlist_at`][`crate::utils::Cache::banlist_at`]. Pass `None` to only check the
+    /// 3-copy rule.
+    /// * `collection` – Collection the deck is built from.
+    pub fn validate(
+        &self,
+        cardinfo: &CardinfoMetaType,
+        banlist: Option<&Banlist>,
+        collection: &Collection,
+    ) -> Vec<DeckViolation> {
+        let mut violations = Vec::new();
+
+        for (section, (min, max)) in [
+            (DeckSection::Main, MAIN_DECK_SIZE),
+            (DeckSection::Extra, EXTRA_DECK_SIZE),
+            (DeckSection::Side, SIDE_DECK_SIZE),
+        ] {
+            let count = self.section(section).len();
+            if count < min || count > max {
+                violations.push(DeckViolation::SectionSize {
+                    section: section,
+                    count: count,
+                    min: min,
+                    max: max,
+                });
+            }
+        }
+
+        // Cards in the side deck can be swapped into either section
+        for (section, cards) in [
+            (DeckSection::Main, &self.main),
+            (DeckSection::Extra, &self.extra),
+        ] {
+            let mut checked = Vec::new();
+
+            for card in cards.iter() {
+                if checked.contains(card) {
+                    continue;
+                }
+                checked.push(*card);
+
+                match section_of(*card, cardinfo) {
+                    Some(expected) if expected != section => {
+                        violations.push(DeckViolation::WrongSection {
+                            id: *card,
+                            section: section,
+                        });
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        for (card, count) in self.counts() {
+            if !cardinfo.contains_key(&card) {
+                violations.push(DeckViolation::UnknownCard(card));
+            }
+
+            if count > MAX_COPIES {
+                violations.push(DeckViolation::TooManyCopies {
+                    id: card,
+                    count: count,
+                });
+            }
+
+            // Unlimited cards are covered by the 3-copy rule
+            let status = banlist.map_or(LimitStatus::Unlimited, |banlist| banlist.status_of(card));
+            if status != LimitStatus::Unlimited && count > status.max_copies() as usize {
+                violations.push(DeckViolation::Banlisted {
+                    id: card,
+                    count: count,
+                    status: status,
+                });
+            }
+
+            let owned = collection.count_of(card);
+            if count > owned as usize {
+                violations.push(DeckViolation::NotOwned {
+                    id: card,
+                    count: count,
+                    owned: owned,
+                });
+            }
+        }
+
+        return violations;
+    }
+
+    /// Checks the deck like [`validate`][`Deck::validate`], against the banlist attached to
+    /// `collection`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` – Loaded card data, usually obtained from [`CACHE`][`crate::utils::CACHE`].
+    /// * `collection` – Collection the deck is built from.
+    pub fn validate_in(&self, cache: &Cache, collection: &Collection) -> Vec<DeckViolation> {
+        let banlist = collection.banlist.resolve(cache);
+
+        self.validate(&cache.cardinfo, banlist.as_ref(), collection)
+    }
+}
+
+/// Returns the section a card has to be played from, or `None` if the card is unknown.
+fn section_of(card: u32, cardinfo: &CardinfoMetaType) -> Option<DeckSection> {
+    cardinfo.get(&card).map(|card| {
+        if card.is_extra_deck() {
+            DeckSection::Extra
+        } else {
+            DeckSection::Main
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,183 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+use crate::test_fixtures;
+use crate::user_data::{Card, Collection};
+
+/// Card data with the main deck cards 1–50 and the extra deck cards 100–120.
+fn init_cardinfo() -> CardinfoMetaType {
+    let mut cardinfo = CardinfoMetaType::new();
+
+    for (ids, card_type) in [
+        (1..=50, "Effect Monster"),
+        (100..=120, "Synchro Tuner Monster"),
+    ] {
+        for id in ids {
+            cardinfo.insert(
+                id,
+                test_fixtures::card(id, &format!("Card {}", id), card_type),
+            );
+        }
+    }
+
+    return cardinfo;
+}
+
+/// Collection that owns 3 copies of every card in `cardinfo`.
+fn init_collection(cardinfo: &CardinfoMetaType) -> Collection {
+    let mut collection = Collection::default();
+
+    for id in cardinfo.keys() {
+        collection.cards.insert(Card::new(*id), 3);
+    }
+
+    return collection;
+}
+
+#[test]
+fn test_from_cards() {
+    let cardinfo = init_cardinfo();
+    let deck = Deck::from_cards(&[1, 100, 2, 101, 999], &cardinfo);
+
+    assert_eq!(deck.main, vec![1, 2, 999]);
+    assert_eq!(deck.extra, vec![100, 101]);
+    assert!(deck.side.is_empty());
+}
+
+#[test]
+fn test_legal_deck() {
+    let cardinfo = init_cardinfo();
+    let collection = init_collection(&cardinfo);
+
+    let deck = Deck {
+        main: (1..=40).collect(),
+        extra: (100..=115).collect(),
+        side: vec![1, 1, 100],
+    };
+    assert_eq!(
+        deck.validate(&cardinfo, None, &collection),
+        vec![DeckViolation::SectionSize {
+            section: DeckSection::Extra,
+            count: 16,
+            min: 0,
+            max: 15,
+        }]
+    );
+
+    let deck = Deck {
+        main: (1..=40).collect(),
+        extra: (100..=114).collect(),
+        side: vec![1, 1, 100],
+    };
+    assert!(deck.validate(&cardinfo, None, &collection).is_empty());
+}
+
+#[test]
+fn test_violations() {
+    let cardinfo = init_cardinfo();
+    let mut collection = init_collection(&cardinfo);
+    collection.cards.insert(Card::new(2), 1);
+
+    let banlist = Banlist::from_iter([(3, LimitStatus::Limited), (4, LimitStatus::Forbidden)]);
+
+    let mut main: Vec<u32> = vec![1, 1, 1, 1, 2, 2, 3, 3, 4, 100, 999];
+    main.extend(11..=40);
+    let deck = Deck {
+        main: main,
+        extra: vec![5],
+        side: Vec::new(),
+    };
+
+    let violations = deck.validate(&cardinfo, Some(&banlist), &collection);
+
+    for violation in [
+        DeckViolation::WrongSection {
+            id: 100,
+            section: DeckSection::Main,
+        },
+        DeckViolation::WrongSection {
+            id: 5,
+            section: DeckSection::Extra,
+        },
+        DeckViolation::UnknownCard(999),
+        DeckViolation::TooManyCopies { id: 1, count: 4 },
+        DeckViolation::NotOwned {
+            id: 1,
+            count: 4,
+            owned: 3,
+        },
+        DeckViolation::NotOwned {
+            id: 2,
+            count: 2,
+            owned: 1,
+        },
+        DeckViolation::Banlisted {
+            id: 3,
+            count: 2,
+            status: LimitStatus::Limited,
+        },
+        DeckViolation::Banlisted {
+            id: 4,
+            count: 1,
+            status: LimitStatus::Forbidden,
+        },
+        DeckViolation::NotOwned {
+            id: 999,
+            count: 1,
+            owned: 0,
+        },
+    ] {
+        assert!(violations.contains(&violation), "{:?}", violation);
+    }
+    assert_eq!(violations.len(), 9);
+}
+
+#[test]
+fn test_ydk() {
+    let cardinfo = init_cardinfo();
+
+    let ydk = "#created by someone\r\n#main\r\n1\r\n2\r\n999\r\n\r\n#extra\r\n100\r\n!side\r\n3\r\n998\r\n";
+    let import = Deck::from_ydk(ydk, &cardinfo).unwrap();
+
+    assert_eq!(
+        import.deck,
+        Deck {
+            main: vec![1, 2],
+            extra: vec![100],
+            side: vec![3],
+        }
+    );
+    assert_eq!(
+        import.unknown,
+        vec![(DeckSection::Main, 999), (DeckSection::Side, 998)]
+    );
+
+    let roundtrip = Deck::from_ydk(&import.deck.to_ydk(), &cardinfo).unwrap();
+    assert_eq!(roundtrip.deck, import.deck);
+    assert!(roundtrip.unknown.is_empty());
+
+    assert!(matches!(
+        Deck::from_ydk("#main\n1\nabc\n", &cardinfo),
+        Err(YdkError::InvalidLine { line: 3, .. })
+    ));
+    assert!(matches!(
+        Deck::from_ydk("1\n#main\n", &cardinfo),
+        Err(YdkError::MissingSection { line: 1 })
+    ));
+}
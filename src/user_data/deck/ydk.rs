@@ -0,0 +1,159 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::ext_data::cardinfo::CardinfoMetaType;
+
+use super::{Deck, DeckSection};
+
+/// Reasons why a `.ydk` file could not be read.
+#[derive(Debug)]
+pub enum YdkError {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// A line is neither a section header, a comment nor a passcode.
+    InvalidLine { line: usize, content: String },
+    /// A passcode appears before the first section header.
+    MissingSection { line: usize },
+}
+
+impl fmt::Display for YdkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YdkError::Io(error) => write!(f, "failed to access the deck file: {}", error),
+            YdkError::InvalidLine { line, content } => {
+                write!(f, "line {} is not a valid passcode: {}", line, content)
+            }
+            YdkError::MissingSection { line } => {
+                write!(f, "line {} contains a passcode outside of a section", line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for YdkError {}
+
+impl From<io::Error> for YdkError {
+    fn from(error: io::Error) -> Self {
+        YdkError::Io(error)
+    }
+}
+
+/// Result of reading a `.ydk` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YdkImport {
+    /// The imported deck. Only contains cards that are known in the card data.
+    pub deck: Deck,
+    /// Passcodes that are not contained in the card data, in the section they were listed in.
+    pub unknown: Vec<(DeckSection, u32)>,
+}
+
+impl Deck {
+    /// Reads a deck in the `.ydk` format used by EDOPro, YGO Omega and Dueling Nexus.
+    ///
+    /// Cards keep the section they are listed in. Passcodes that are not contained in `cardinfo`
+    /// are not added to the deck, but returned in [`YdkImport::unknown`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ydk` – Content of a `.ydk` file.
+    /// * `cardinfo` – Card data used to look up passcodes.
+    pub fn from_ydk(ydk: &str, cardinfo: &CardinfoMetaType) -> Result<YdkImport, YdkError> {
+        let mut import = YdkImport {
+            deck: Deck::default(),
+            unknown: Vec::new(),
+        };
+        let mut section = None;
+
+        for (i, line) in ydk.lines().enumerate() {
+            let line = line.trim();
+
+            match line {
+                "" => continue,
+                "#main" => section = Some(DeckSection::Main),
+                "#extra" => section = Some(DeckSection::Extra),
+                "!side" => section = Some(DeckSection::Side),
+                // Other lines starting with `#`, e.g. `#created by ...`, are comments
+                _ if line.starts_with('#') => continue,
+                _ => {
+                    let passcode = line.parse::<u32>().map_err(|_| YdkError::InvalidLine {
+                        line: i + 1,
+                        content: line.to_string(),
+                    })?;
+                    let section = section.ok_or(YdkError::MissingSection { line: i + 1 })?;
+
+                    if !cardinfo.contains_key(&passcode) {
+                        import.unknown.push((section, passcode));
+                        continue;
+                    }
+
+                    match section {
+                        DeckSection::Main => import.deck.main.push(passcode),
+                        DeckSection::Extra => import.deck.extra.push(passcode),
+                        DeckSection::Side => import.deck.side.push(passcode),
+                    }
+                }
+            }
+        }
+
+        return Ok(import);
+    }
+
+    /// Reads a `.ydk` file, like [`from_ydk`][`Deck::from_ydk`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` – Path to the `.ydk` file.
+    /// * `cardinfo` – Card data used to look up passcodes.
+    pub fn read_ydk(path: &Path, cardinfo: &CardinfoMetaType) -> Result<YdkImport, YdkError> {
+        Deck::from_ydk(&fs::read_to_string(path)?, cardinfo)
+    }
+
+    /// Returns the deck in the `.ydk` format.
+    pub fn to_ydk(&self) -> String {
+        let mut ydk = String::from("#created by YGO Destiny\n");
+
+        for (header, cards) in [
+            ("#main", &self.main),
+            ("#extra", &self.extra),
+            ("!side", &self.side),
+        ] {
+            ydk.push_str(header);
+            ydk.push('\n');
+
+            for card in cards.iter() {
+                ydk.push_str(&card.to_string());
+                ydk.push('\n');
+            }
+        }
+
+        return ydk;
+    }
+
+    /// Writes the deck to a `.ydk` file, so that it can be played in other simulators.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` – Path to the `.ydk` file. An existing file is overwritten.
+    pub fn write_ydk(&self, path: &Path) -> Result<(), YdkError> {
+        Ok(fs::write(path, self.to_ydk())?)
+    }
+}
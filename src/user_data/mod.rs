@@ -17,10 +17,64 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 /// Module containing a serializable card collection data type.
 mod collection;
+/// Module containing user-defined banlists.
+mod custom_banlist;
+/// Module containing a deck data type and its validation.
+mod deck;
 
 pub use collection::{
+    Action,
+    ActionSchedule,
+    ActionStep,
+    ArchetypeDrafter,
+    BattlePackSession,
+    BattlePackSettings,
+    BonusCard,
+    BonusDraft,
+    Card,
+    Change,
+    ChangeContent,
+    ChoiceDraftSession,
+    ChoiceSettings,
+    CoherentDrafter,
     Collection,
+    CollectionError,
     DraftBox,
+    DraftBoxMeta,
+    DraftError,
+    DraftSession,
+    DraftSessionError,
+    Drafter,
+    MainDraft,
     MetaData,
+    OpenedPack,
+    PassDirection,
+    PendingDraft,
+    Product,
+    RandomDrafter,
+    RareDrafter,
+    SealedPool,
+    SealedPoolBuilder,
+    SealedPoolError,
+    SetRotation,
+    StandardDraftSession,
+    StandardSettings,
+    BATTLE_PACK_TAG,
     LAST_CHANGED_FORMAT,
 };
+pub use custom_banlist::{
+    BanlistRef,
+    CustomBanlist,
+    CustomBanlistError,
+};
+pub use deck::{
+    Deck,
+    DeckSection,
+    DeckViolation,
+    YdkError,
+    YdkImport,
+    EXTRA_DECK_SIZE,
+    MAIN_DECK_SIZE,
+    MAX_COPIES,
+    SIDE_DECK_SIZE,
+};
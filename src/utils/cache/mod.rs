@@ -0,0 +1,326 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! # Examples
+//!
+//! ```rust
+//! use ygod_core::utils::{http, CACHE};
+//!
+//! // Load local files into cache
+//! http::load_local_data();
+//!
+//! // Access cached data
+//! &CACHE.lock().unwrap().banlists;
+//! &CACHE.lock().unwrap().cardinfo;
+//! &CACHE.lock().unwrap().cardsets;
+//!
+//! // Get all cards from the 3 Battle Pack main sets
+//! let battle_pack_cards = CACHE.lock().unwrap().get_cards_from_sets(
+//!     vec![
+//!         "Battle Pack: Epic Dawn".to_string(),
+//!         "Battle Pack 2: War of the Giants".to_string(),
+//!         "Battle Pack 3: Monster League".to_string(),
+//!     ]
+//! );
+//!
+//! // Look up cards with the indexes built when loading the data
+//! let blue_eyes_cards = CACHE.lock().unwrap().cards_of_archetype("Blue-Eyes").to_vec();
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+
+use crate::ext_data::banlists::{Banlist, BanlistSource};
+use crate::ext_data::cardinfo::{Attribute, Race};
+use crate::ext_data::cardsets::Printing;
+use crate::ext_data::{banlists, cardinfo, cardsets};
+use crate::utils::card_search::{self, Filter, NameIndex, SortBy};
+
+/// Container for global variables. This should generally only be accessed via [`CACHE`].
+///
+/// The indexes of the card data are not updated automatically. Call
+/// [`rebuild_indexes`][`Cache::rebuild_indexes`] after changing
+/// [`cardinfo`][`Cache::cardinfo`] or [`cardsets`][`Cache::cardsets`].
+#[derive(Default)]
+pub struct Cache {
+    pub banlists: banlists::BanlistSourcesMetaType,
+    pub cardinfo: cardinfo::CardinfoMetaType,
+    pub cardsets: cardsets::CardsetsMetaType,
+    /// Fuzzy name index of [`cardinfo`][`Cache::cardinfo`].
+    pub name_index: NameIndex,
+    index: CardIndex,
+}
+
+/// Lookup tables of the card data. All card ids are sorted.
+#[derive(Default)]
+struct CardIndex {
+    /// Card ids by lowercase archetype.
+    archetypes: HashMap<String, Vec<u32>>,
+    attributes: HashMap<Attribute, Vec<u32>>,
+    races: HashMap<Race, Vec<u32>>,
+    /// Card ids by normalized name, see [`card_search::normalize`]. Different names can have the
+    /// same normalized name.
+    names: HashMap<String, Vec<u32>>,
+    /// Sorted names of the card sets each card is printed in.
+    sets: HashMap<u32, Vec<String>>,
+    /// Printings by set code. If a code is printed in several rarities, only one of them is kept.
+    codes: HashMap<String, Printing>,
+}
+
+impl CardIndex {
+    /// Constructs the lookup tables of `cardinfo` and `cardsets`.
+    fn new(cardinfo: &cardinfo::CardinfoMetaType, cardsets: &cardsets::CardsetsMetaType) -> Self {
+        let mut index = Self::default();
+
+        for card in cardinfo.values() {
+            if let Some(archetype) = &card.archetype {
+                index
+                    .archetypes
+                    .entry(archetype.to_lowercase())
+                    .or_default()
+                    .push(card.id);
+            }
+            if let Some(attribute) = card.attribute {
+                index.attributes.entry(attribute).or_default().push(card.id);
+            }
+            if let Some(race) = card.race {
+                index.races.entry(race).or_default().push(card.id);
+            }
+            index
+                .names
+                .entry(card_search::normalize(&card.name))
+                .or_default()
+                .push(card.id);
+        }
+
+        for (name, set) in cardsets.iter() {
+            for id in set.cards.iter().flatten() {
+                index.sets.entry(*id).or_default().push(name.clone());
+            }
+            for printing in set.printings.iter() {
+                index
+                    .codes
+                    .entry(printing.code.clone())
+                    .or_insert_with(|| printing.clone());
+            }
+        }
+
+        for ids in index
+            .archetypes
+            .values_mut()
+            .chain(index.attributes.values_mut())
+            .chain(index.races.values_mut())
+            .chain(index.names.values_mut())
+        {
+            ids.sort_unstable();
+        }
+        for sets in index.sets.values_mut() {
+            sets.sort_unstable();
+            sets.dedup();
+        }
+
+        return index;
+    }
+}
+
+impl Cache {
+    /// Rebuilds the indexes of the card data, including [`name_index`][`Cache::name_index`].
+    pub fn rebuild_indexes(&mut self) {
+        self.index = CardIndex::new(&self.cardinfo, &self.cardsets);
+        self.name_index = NameIndex::new(&self.cardinfo);
+    }
+
+    /// Returns the ids of all cards of an archetype. Case is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `archetype` – Name of the archetype, e.g. `Blue-Eyes`.
+    pub fn cards_of_archetype(&self, archetype: &str) -> &[u32] {
+        self.index
+            .archetypes
+            .get(&archetype.to_lowercase())
+            .map_or(&[], |ids| ids.as_slice())
+    }
+
+    /// Returns the ids of all monsters with `attribute`.
+    pub fn cards_with_attribute(&self, attribute: Attribute) -> &[u32] {
+        self.index
+            .attributes
+            .get(&attribute)
+            .map_or(&[], |ids| ids.as_slice())
+    }
+
+    /// Returns the ids of all monsters of the type `race`.
+    pub fn cards_of_race(&self, race: Race) -> &[u32] {
+        self.index
+            .races
+            .get(&race)
+            .map_or(&[], |ids| ids.as_slice())
+    }
+
+    /// Returns the card with the name `name`. Case, punctuation and extra spaces are ignored. If
+    /// several cards match, the one whose name is exactly `name` is preferred, otherwise the one
+    /// with the lowest id. See [`name_index`][`Cache::name_index`] for misspelled names.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the card, e.g. `blue eyes white dragon`.
+    pub fn card_by_name(&self, name: &str) -> Option<&cardinfo::Card> {
+        let cards: Vec<&cardinfo::Card> = self
+            .cards_by_name(name)
+            .iter()
+            .filter_map(|id| self.cardinfo.get(id))
+            .collect();
+
+        return cards
+            .iter()
+            .find(|card| card.name == name)
+            .or(cards.first())
+            .copied();
+    }
+
+    /// Returns the ids of all cards with the name `name`, ignoring case, punctuation and extra
+    /// spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` – Name of the cards, e.g. `blue eyes white dragon`.
+    pub fn cards_by_name(&self, name: &str) -> &[u32] {
+        self.index
+            .names
+            .get(&card_search::normalize(name))
+            .map_or(&[], |ids| ids.as_slice())
+    }
+
+    /// Returns the names of all card sets a card is printed in, sorted by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    pub fn sets_of(&self, id: u32) -> &[String] {
+        self.index.sets.get(&id).map_or(&[], |sets| sets.as_slice())
+    }
+
+    /// Turns a listing of card set names into a listing of all card ids in those sets by looking
+    /// them up in `self.cardsets`. Duplicates are *not* handled and must be dealt with externally.
+    ///
+    /// # Arguments
+    ///
+    /// * `sets` – A listing of card set names.
+    pub fn get_cards_from_sets(&self, sets: Vec<String>) -> Vec<u32> {
+        let mut cards = Vec::new();
+
+        for set in sets {
+            cards.extend(self.cardsets[&set].cards.as_ref().unwrap());
+        }
+
+        return cards;
+    }
+
+    /// Turns a listing of card set names into a listing of all printings in those sets by looking
+    /// them up in `self.cardsets`. Like [`get_cards_from_sets`][`Self::get_cards_from_sets`],
+    /// duplicates are *not* handled. Unknown sets are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `sets` – A listing of card set names.
+    pub fn get_printings_from_sets(&self, sets: Vec<String>) -> Vec<Printing> {
+        let mut printings = Vec::new();
+
+        for set in sets.iter().filter_map(|set| self.cardsets.get(set)) {
+            printings.extend(set.printings.iter().cloned());
+        }
+
+        return printings;
+    }
+
+    /// Returns the printing with the set code `code`, e.g. `LOB-EN001`, or `None` if no card was
+    /// printed with that code. Uses the indexes, see [`rebuild_indexes`][`Cache::rebuild_indexes`].
+    ///
+    /// # Arguments
+    ///
+    /// * `code` – Set code of the printing.
+    pub fn get_printing(&self, code: &str) -> Option<&Printing> {
+        self.index.codes.get(code)
+    }
+
+    /// Returns all printings of a card, sorted by set code.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` – Id of the card.
+    pub fn get_printings_of(&self, id: u32) -> Vec<&Printing> {
+        let mut printings: Vec<&Printing> = self
+            .sets_of(id)
+            .iter()
+            .filter_map(|set| self.cardsets.get(set))
+            .flat_map(|set| set.printings.iter())
+            .filter(|printing| printing.id == id)
+            .collect();
+        printings.sort_by(|a, b| a.code.cmp(&b.code).then(a.rarity.cmp(&b.rarity)));
+
+        return printings;
+    }
+
+    /// Returns all cards that match `filter`, sorted by `sort_by`. See [`card_search::search`].
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` – Condition the returned cards match.
+    /// * `sort_by` – Order of the returned cards.
+    pub fn search(&self, filter: &Filter, sort_by: SortBy) -> Vec<&cardinfo::Card> {
+        card_search::search(&self.cardinfo, filter, sort_by)
+    }
+
+    /// Returns the banlist of `source` that was in effect on `date`. See
+    /// [`banlists::banlist_at`].
+    ///
+    /// # Arguments
+    ///
+    /// * `source` – Region or format of the banlist.
+    /// * `date` – Date to look up.
+    pub fn banlist_at(
+        &self,
+        source: &BanlistSource,
+        date: NaiveDate,
+    ) -> Option<(&NaiveDate, &Banlist)> {
+        banlists::banlist_at(self.banlists.get(source)?, date)
+    }
+
+    /// Returns the TCG banlist that was in effect when a set was released in the TCG. Returns `None`
+    /// if the set or its release date is unknown, or if it was released before the first banlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` – Name of the card set.
+    pub fn banlist_at_release(&self, set: &str) -> Option<(&NaiveDate, &Banlist)> {
+        let date = self.cardsets.get(set)?.date.as_ref()?;
+
+        self.banlist_at(
+            &BanlistSource::Tcg,
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?,
+        )
+    }
+}
+
+/// Cache data container. See [`Cache`] for methods and fields.
+pub static CACHE: Lazy<Mutex<Cache>> = Lazy::new(|| Mutex::new(Cache::default()));
+
+#[cfg(test)]
+mod tests;
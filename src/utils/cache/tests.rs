@@ -0,0 +1,146 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+use crate::test_fixtures;
+
+const CARDINFO: &str = r#"{"data": [
+    {"id": 89631139, "name": "Blue-Eyes White Dragon", "type": "Normal Monster", "desc": "",
+     "atk": 3000, "def": 2500, "level": 8, "race": "Dragon", "attribute": "LIGHT",
+     "archetype": "Blue-Eyes",
+     "card_sets": [
+        {"set_name": "Legend of Blue Eyes White Dragon", "set_code": "LOB-001",
+         "set_rarity": "Ultra Rare"},
+        {"set_name": "Starter Deck: Kaiba", "set_code": "SDK-001", "set_rarity": "Ultra Rare"}
+     ]},
+    {"id": 38517737, "name": "Blue-Eyes Alternative White Dragon", "type": "Effect Monster",
+     "desc": "", "atk": 3000, "def": 2500, "level": 8, "race": "Dragon", "attribute": "LIGHT",
+     "archetype": "Blue-Eyes"},
+    {"id": 46986414, "name": "Dark Magician", "type": "Normal Monster", "desc": "",
+     "atk": 2500, "def": 2100, "level": 7, "race": "Spellcaster", "attribute": "DARK",
+     "archetype": "Dark Magician",
+     "card_sets": [
+        {"set_name": "Legend of Blue Eyes White Dragon", "set_code": "LOB-005",
+         "set_rarity": "Ultra Rare"}
+     ]}
+]}"#;
+
+/// Cache with the cards of `CARDINFO` and their sets, with indexes.
+fn init_cache() -> Cache {
+    test_fixtures::cache_from_cardinfo(CARDINFO)
+}
+
+#[test]
+fn test_indexes() {
+    let cache = init_cache();
+
+    assert_eq!(cache.cards_of_archetype("blue-eyes"), &[38517737, 89631139]);
+    assert!(cache.cards_of_archetype("Red-Eyes").is_empty());
+    assert_eq!(cache.cards_with_attribute(Attribute::Dark), &[46986414]);
+    assert_eq!(cache.cards_of_race(Race::Dragon).len(), 2);
+    assert!(cache.cards_of_race(Race::Zombie).is_empty());
+
+    assert_eq!(
+        cache.card_by_name("BLUE EYES WHITE DRAGON").unwrap().id,
+        89631139
+    );
+    assert!(cache.card_by_name("Blue-Eyes White Dragn").is_none());
+
+    assert_eq!(
+        cache.sets_of(89631139),
+        &[
+            "Legend of Blue Eyes White Dragon".to_string(),
+            "Starter Deck: Kaiba".to_string()
+        ]
+    );
+    assert!(cache.sets_of(38517737).is_empty());
+    assert_eq!(cache.get_printings_of(89631139)[0].code, "LOB-001");
+    assert_eq!(cache.name_index.len(), 3);
+}
+
+#[test]
+fn test_printings_from_sets() {
+    let cache = init_cache();
+
+    let printings = cache.get_printings_from_sets(vec![
+        "Legend of Blue Eyes White Dragon".to_string(),
+        "Unknown Set".to_string(),
+    ]);
+    assert_eq!(printings.len(), 2);
+    assert!(cache
+        .get_printings_from_sets(vec!["Unknown Set".to_string()])
+        .is_empty());
+}
+
+#[test]
+fn test_printings() {
+    let cache = init_cache();
+
+    let printing = cache.get_printing("SDK-001").unwrap();
+    assert_eq!(printing.id, 89631139);
+    assert_eq!(printing.set_name, "Starter Deck: Kaiba");
+    assert_eq!(cache.get_printing("LOB-EN001"), None);
+
+    let printings = cache.get_printings_of(89631139);
+    assert_eq!(printings.len(), 2);
+    assert_eq!(printings[1].code, "SDK-001");
+    assert!(cache.get_printings_of(38517737).is_empty());
+
+    // Sets that are missing from the card data are skipped
+    let mut cache = init_cache();
+    cache.cardsets.remove("Starter Deck: Kaiba");
+    assert_eq!(cache.get_printings_of(89631139).len(), 1);
+}
+
+#[test]
+fn test_name_collisions() {
+    let mut cache = init_cache();
+    let mut card = cache.cardinfo[&89631139].clone();
+    card.id = 1;
+    card.name = "Blue Eyes White Dragon".to_string();
+    cache.cardinfo.insert(card.id, card);
+    cache.rebuild_indexes();
+
+    assert_eq!(
+        cache.cards_by_name("blue-eyes white dragon"),
+        &[1, 89631139]
+    );
+    assert_eq!(
+        cache.card_by_name("Blue-Eyes White Dragon").unwrap().id,
+        89631139
+    );
+    assert_eq!(cache.card_by_name("Blue Eyes White Dragon").unwrap().id, 1);
+    assert_eq!(cache.card_by_name("BLUE EYES WHITE DRAGON").unwrap().id, 1);
+    assert!(cache.cards_by_name("Red-Eyes Black Dragon").is_empty());
+}
+
+#[test]
+fn test_rebuild_indexes() {
+    let mut cache = init_cache();
+
+    cache.cardinfo.remove(&38517737);
+    cache.cardsets.remove("Starter Deck: Kaiba");
+    // Indexes are only updated on request
+    assert_eq!(cache.cards_of_archetype("Blue-Eyes").len(), 2);
+    assert!(cache.get_printing("SDK-001").is_some());
+
+    cache.rebuild_indexes();
+    assert_eq!(cache.cards_of_archetype("Blue-Eyes"), &[89631139]);
+    assert_eq!(cache.name_index.len(), 2);
+    assert_eq!(cache.get_printing("SDK-001"), None);
+}
@@ -52,13 +52,49 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 //! let card_pool: Vec<u32> = vec![0, 1, 2, 3, 4, 5];
 //! let mut cg = CardGenerator::new_dedup(card_pool, duplicates);
 //! ```
+//!
+//! ## Seeded CardGenerator
+//!
+//! Generators constructed from the same seed and card pool generate the same cards. A seed can be
+//! derived from a short string that players can share.
+//!
+//! ```rust
+//! use ygod_core::utils::card_gen::{seed_from_str, CardGenerator};
+//!
+//! let seed = seed_from_str("friday-draft");
+//!
+//! let mut cg_a = CardGenerator::from_seed(vec![0, 1, 2, 3, 4, 5], 3, seed);
+//! let mut cg_b = CardGenerator::from_seed(vec![0, 1, 2, 3, 4, 5], 3, seed);
+//!
+//! assert_eq!(cg_a.generate(5), cg_b.generate(5));
+//!
+//! // Any other random number generator can be used as well
+//! use rand::{rngs::StdRng, SeedableRng};
+//!
+//! let mut cg = CardGenerator::with_rng(vec![0, 1, 2], 1, StdRng::seed_from_u64(seed));
+//! assert_eq!(cg.generate(3).len(), 3);
+//! ```
 
-use rand::rngs::ThreadRng;
-use rand::Rng;
+/// Rarity-aware pack generation.
+mod pack_gen;
+
+pub use pack_gen::{PackGenerator, PackLayout, RarityOdds, RaritySlot};
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::banlists::Banlist;
 
 /// Basic card generator that disregards rarities. Supports limiting the number of times a card can
-/// be generated.
-pub struct CardGenerator {
+/// be generated. See [`PackGenerator`] for generating packs with rarity slots.
+///
+/// The generator can be serialized including the state of its random number generator, so a
+/// restored generator continues exactly where the original one stopped. By default [`ChaCha8Rng`]
+/// is used, but any other random number generator can be passed to
+/// [`with_rng`][`CardGenerator::with_rng`].
+#[derive(Serialize, Deserialize)]
+pub struct CardGenerator<R = ChaCha8Rng> {
     /// Card pool to generate cards from.
     cards: Vec<u32>,
     /// Number of times each card is left in the card pool. If `None` any card can show up an
@@ -66,17 +102,110 @@ pub struct CardGenerator {
     card_quantities: Option<Vec<usize>>,
     /// Number of *unique* cards remaining in the card pool.
     remaining_cards: usize,
-    rng: ThreadRng,
+    rng: R,
+}
+
+/// Returns a seed for [`CardGenerator::from_seed`] derived from `seed`.
+///
+/// The result is stable across platforms and versions (64 bit FNV-1a hash), so the same string
+/// always results in the same cards.
+pub fn seed_from_str(seed: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in seed.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    return hash;
 }
 
 impl CardGenerator {
-    /// Constructs a new [`CardGenerator`].
+    /// Constructs a new [`CardGenerator`] with a randomly seeded random number generator.
     ///
     /// # Arguments
     ///
     /// * `cards` – The card pool of the card generator.
     /// * `dups` – The number of times each card can be generated. Set to `0` for no limit.
     pub fn new(cards: Vec<u32>, dups: usize) -> CardGenerator {
+        CardGenerator::with_rng(cards, dups, ChaCha8Rng::from_entropy())
+    }
+
+    /// Constructs a new [`CardGenerator`] that always generates the same cards for the same `seed`
+    /// and card pool. Same arguments as [`new`][`CardGenerator::new`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the random number generator. See [`seed_from_str`] to use a string.
+    pub fn from_seed(cards: Vec<u32>, dups: usize, seed: u64) -> CardGenerator {
+        CardGenerator::with_rng(cards, dups, ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Constructs a [`CardGenerator`], but de-duplicates the card pool first. Same arguments as
+    /// [`new`][`CardGenerator::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cards` – The card pool of the card generator. The cards will be de-duplicated.
+    /// * `dups` – The number of times each card can be generated. Set to `0` for no limit.
+    pub fn new_dedup(cards: Vec<u32>, dups: usize) -> CardGenerator {
+        let mut cards_mut = cards.to_vec();
+        cards_mut.sort_unstable();
+        cards_mut.dedup();
+
+        return CardGenerator::new(cards_mut, dups);
+    }
+
+    /// Constructs a seeded [`CardGenerator`], but de-duplicates the card pool first. Same
+    /// arguments as [`from_seed`][`CardGenerator::from_seed`].
+    pub fn from_seed_dedup(cards: Vec<u32>, dups: usize, seed: u64) -> CardGenerator {
+        let mut cards_mut = cards.to_vec();
+        cards_mut.sort_unstable();
+        cards_mut.dedup();
+
+        return CardGenerator::from_seed(cards_mut, dups, seed);
+    }
+
+    /// Constructs a [`CardGenerator`] that never generates more copies of a card than `banlist`
+    /// allows in a deck. Forbidden cards are removed from the card pool, limited cards are
+    /// generated at most once and semi-limited cards at most twice. The card pool is de-duplicated.
+    ///
+    /// # Arguments
+    ///
+    /// * `cards` – The card pool of the card generator. The cards will be de-duplicated.
+    /// * `dups` – The number of times each unlimited card can be generated. Set to `0` to allow as
+    /// many copies as a deck can contain.
+    /// * `banlist` – Banlist that limits the number of copies of each card, e.g. the result of
+    /// [`BanlistRef::resolve`][`crate::user_data::BanlistRef::resolve`].
+    pub fn from_banlist(cards: Vec<u32>, dups: usize, banlist: &Banlist) -> CardGenerator {
+        CardGenerator::with_rng_banlist(cards, dups, banlist, ChaCha8Rng::from_entropy())
+    }
+
+    /// Constructs a seeded [`CardGenerator`] that respects a banlist. Same arguments as
+    /// [`from_banlist`][`CardGenerator::from_banlist`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the random number generator. See [`seed_from_str`] to use a string.
+    pub fn from_seed_banlist(
+        cards: Vec<u32>,
+        dups: usize,
+        banlist: &Banlist,
+        seed: u64,
+    ) -> CardGenerator {
+        CardGenerator::with_rng_banlist(cards, dups, banlist, ChaCha8Rng::seed_from_u64(seed))
+    }
+}
+
+impl<R: RngCore> CardGenerator<R> {
+    /// Constructs a new [`CardGenerator`] that uses `rng` to select cards.
+    ///
+    /// # Arguments
+    ///
+    /// * `cards` – The card pool of the card generator.
+    /// * `dups` – The number of times each card can be generated. Set to `0` for no limit.
+    /// * `rng` – Random number generator, e.g. a [`SeedableRng`] for reproducible results.
+    pub fn with_rng(cards: Vec<u32>, dups: usize, rng: R) -> CardGenerator<R> {
         let remaining_cards = cards.len();
 
         let quants = if dups > 0 {
@@ -89,27 +218,56 @@ impl CardGenerator {
             cards: cards,
             card_quantities: quants,
             remaining_cards: remaining_cards,
-            rng: rand::thread_rng(),
+            rng: rng,
         };
 
         return card_gen;
     }
 
-    /// Constructs a [`CardGenerator`], but de-duplicates the card pool first. Same arguments as
-    /// [`new`][`CardGenerator::new`].
+    /// Constructs a new [`CardGenerator`] that respects a banlist and uses `rng` to select cards.
+    /// Same arguments as [`from_banlist`][`CardGenerator::from_banlist`], plus:
     ///
     /// # Arguments
     ///
-    /// * `cards` – The card pool of the card generator. The cards will be de-duplicated.
-    /// * `dups` – The number of times each card can be generated. Set to `0` for no limit.
-    pub fn new_dedup(cards: Vec<u32>, dups: usize) -> CardGenerator {
+    /// * `rng` – Random number generator.
+    pub fn with_rng_banlist(
+        cards: Vec<u32>,
+        dups: usize,
+        banlist: &Banlist,
+        rng: R,
+    ) -> CardGenerator<R> {
         let mut cards_mut = cards.to_vec();
         cards_mut.sort_unstable();
         cards_mut.dedup();
 
-        return CardGenerator::new(cards_mut, dups);
+        let mut pool = Vec::new();
+        let mut quants = Vec::new();
+
+        for card in cards_mut {
+            let max_copies = banlist.status_of(card).max_copies() as usize;
+            let quantity = if dups > 0 {
+                dups.min(max_copies)
+            } else {
+                max_copies
+            };
+
+            // Forbidden cards are not added to the card pool
+            if quantity > 0 {
+                pool.push(card);
+                quants.push(quantity);
+            }
+        }
+
+        CardGenerator {
+            remaining_cards: pool.len(),
+            cards: pool,
+            card_quantities: Some(quants),
+            rng: rng,
+        }
     }
 
+    /// Generates up to `n` cards. Fewer cards are returned if the card pool runs out.
+    ///
     /// # Arguments
     ///
     /// * `n` – Number of cards to generate.
@@ -117,6 +275,10 @@ impl CardGenerator {
         let mut generated = Vec::new();
 
         for _ in 0..n {
+            if self.remaining_cards == 0 {
+                break;
+            }
+
             let rand_num: usize = self.rng.gen_range(0..self.remaining_cards);
             generated.push(self.cards[rand_num]);
 
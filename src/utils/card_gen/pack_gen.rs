@@ -0,0 +1,338 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::collections::HashMap;
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::banlists::{Banlist, LimitStatus};
+use crate::ext_data::cardsets::Printing;
+
+/// Rarities that share a chance of showing up in a [`RaritySlot`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RarityOdds {
+    /// Set rarities as they are named in the card data, e.g. `Super Rare`.
+    pub rarities: Vec<String>,
+    /// Relative weight compared to the other odds of the slot.
+    pub weight: u32,
+}
+
+/// A number of slots in a pack that are filled with the same rarity odds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RaritySlot {
+    /// Number of cards generated for this slot.
+    pub count: usize,
+    pub odds: Vec<RarityOdds>,
+}
+
+impl RaritySlot {
+    /// Constructs a slot that always generates cards of one of `rarities`.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` – Number of cards generated for the slot.
+    /// * `rarities` – Set rarities the cards are generated from.
+    pub fn fixed(count: usize, rarities: &[&str]) -> Self {
+        Self {
+            count: count,
+            odds: vec![RarityOdds {
+                rarities: rarities.iter().map(|rarity| rarity.to_string()).collect(),
+                weight: 1,
+            }],
+        }
+    }
+}
+
+/// Layout of the slots of a pack.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PackLayout {
+    pub slots: Vec<RaritySlot>,
+}
+
+impl PackLayout {
+    /// Layout of a typical booster pack: 7 commons, 1 rare and 1 super rare, which is replaced by
+    /// an ultra rare in 1 of 6 packs and by a secret rare in 1 of 12 packs.
+    pub fn booster() -> Self {
+        Self {
+            slots: vec![
+                RaritySlot::fixed(7, &["Common", "Short Print"]),
+                RaritySlot::fixed(1, &["Rare"]),
+                RaritySlot {
+                    count: 1,
+                    odds: vec![
+                        RarityOdds {
+                            rarities: vec!["Super Rare".to_string()],
+                            weight: 9,
+                        },
+                        RarityOdds {
+                            rarities: vec!["Ultra Rare".to_string()],
+                            weight: 2,
+                        },
+                        RarityOdds {
+                            rarities: vec!["Secret Rare".to_string()],
+                            weight: 1,
+                        },
+                    ],
+                },
+            ],
+        }
+    }
+
+    /// Number of cards in a pack.
+    pub fn size(&self) -> usize {
+        self.slots.iter().map(|slot| slot.count).sum()
+    }
+}
+
+/// Generates packs that resemble real products by filling the slots of a [`PackLayout`] with
+/// printings of the matching rarity.
+///
+/// Odds whose rarities are not printed in the card pool are skipped. If none of the odds of a slot
+/// can be used, the slot is filled from the whole card pool. Like in real packs, cards can show up
+/// any number of times, unless the generator was constructed with a banlist.
+#[derive(Serialize, Deserialize)]
+pub struct PackGenerator<R = ChaCha8Rng> {
+    layout: PackLayout,
+    /// Printings of the card pool, grouped by rarity.
+    pools: HashMap<String, Vec<Printing>>,
+    /// All printings of the card pool.
+    printings: Vec<Printing>,
+    /// Number of copies that can still be generated of each limited and semi-limited card.
+    limits: HashMap<u32, usize>,
+    rng: R,
+}
+
+impl PackGenerator {
+    /// Constructs a new [`PackGenerator`] with a randomly seeded random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `printings` – The card pool of the pack generator, e.g. from
+    /// [`Cache::get_printings_from_sets`][`crate::utils::Cache::get_printings_from_sets`].
+    /// * `layout` – Layout of the generated packs.
+    pub fn new(printings: Vec<Printing>, layout: PackLayout) -> PackGenerator {
+        PackGenerator::with_rng(printings, layout, ChaCha8Rng::from_entropy())
+    }
+
+    /// Constructs a new [`PackGenerator`] that always generates the same packs for the same
+    /// `seed` and card pool. Same arguments as [`new`][`PackGenerator::new`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the random number generator.
+    pub fn from_seed(printings: Vec<Printing>, layout: PackLayout, seed: u64) -> PackGenerator {
+        PackGenerator::with_rng(printings, layout, ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Constructs a [`PackGenerator`] that never generates more copies of a card than `banlist`
+    /// allows in a deck. Forbidden cards are removed from the card pool, limited cards are
+    /// generated at most once and semi-limited cards at most twice. Unlimited cards can show up
+    /// any number of times.
+    ///
+    /// # Arguments
+    ///
+    /// * `printings` – The card pool of the pack generator.
+    /// * `layout` – Layout of the generated packs.
+    /// * `banlist` – Banlist that limits the number of copies of each card.
+    pub fn from_banlist(
+        printings: Vec<Printing>,
+        layout: PackLayout,
+        banlist: &Banlist,
+    ) -> PackGenerator {
+        PackGenerator::with_rng_banlist(printings, layout, banlist, ChaCha8Rng::from_entropy())
+    }
+
+    /// Constructs a seeded [`PackGenerator`] that respects a banlist. Same arguments as
+    /// [`from_banlist`][`PackGenerator::from_banlist`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` – Seed of the random number generator.
+    pub fn from_seed_banlist(
+        printings: Vec<Printing>,
+        layout: PackLayout,
+        banlist: &Banlist,
+        seed: u64,
+    ) -> PackGenerator {
+        PackGenerator::with_rng_banlist(printings, layout, banlist, ChaCha8Rng::seed_from_u64(seed))
+    }
+}
+
+impl<R: RngCore> PackGenerator<R> {
+    /// Constructs a new [`PackGenerator`] that uses `rng` to select cards.
+    ///
+    /// # Arguments
+    ///
+    /// * `printings` – The card pool of the pack generator. Duplicates are removed.
+    /// * `layout` – Layout of the generated packs.
+    /// * `rng` – Random number generator.
+    pub fn with_rng(printings: Vec<Printing>, layout: PackLayout, rng: R) -> PackGenerator<R> {
+        let mut unique: Vec<Printing> = Vec::new();
+        for printing in printings {
+            if !unique.contains(&printing) {
+                unique.push(printing);
+            }
+        }
+
+        let mut pools: HashMap<String, Vec<Printing>> = HashMap::new();
+        for printing in unique.iter() {
+            pools
+                .entry(printing.rarity.clone())
+                .or_default()
+                .push(printing.clone());
+        }
+
+        PackGenerator {
+            layout: layout,
+            pools: pools,
+            printings: unique,
+            limits: HashMap::new(),
+            rng: rng,
+        }
+    }
+
+    /// Constructs a new [`PackGenerator`] that respects a banlist and uses `rng` to select cards.
+    /// Same arguments as [`from_banlist`][`PackGenerator::from_banlist`], plus:
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` – Random number generator.
+    pub fn with_rng_banlist(
+        printings: Vec<Printing>,
+        layout: PackLayout,
+        banlist: &Banlist,
+        rng: R,
+    ) -> PackGenerator<R> {
+        let mut printings = printings;
+        printings.retain(|printing| !banlist.is_forbidden(printing.id));
+
+        let mut generator = PackGenerator::with_rng(printings, layout, rng);
+        for printing in generator.printings.iter() {
+            let status = banlist.status_of(printing.id);
+            if status != LimitStatus::Unlimited {
+                generator
+                    .limits
+                    .insert(printing.id, status.max_copies() as usize);
+            }
+        }
+
+        return generator;
+    }
+
+    /// Layout of the generated packs.
+    pub fn layout(&self) -> &PackLayout {
+        &self.layout
+    }
+
+    /// Generates one pack. The printings are ordered like the slots of the layout. Returns a
+    /// smaller pack if the card pool runs out.
+    pub fn generate(&mut self) -> Vec<Printing> {
+        let mut pack = Vec::new();
+
+        for slot in 0..self.layout.slots.len() {
+            for _ in 0..self.layout.slots[slot].count {
+                if self.printings.is_empty() {
+                    return pack;
+                }
+
+                let printing = self.select(slot);
+                self.take(printing.id);
+                pack.push(printing);
+            }
+        }
+
+        return pack;
+    }
+
+    /// Generates multiple packs, like [`generate`][`PackGenerator::generate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `packs` – Number of packs to generate. This is equal to `result.len()`.
+    pub fn batch_generate(&mut self, packs: usize) -> Vec<Vec<Printing>> {
+        (0..packs).map(|_| self.generate()).collect()
+    }
+
+    /// Selects a printing for a card of the slot with index `slot`.
+    fn select(&mut self, slot: usize) -> Printing {
+        // Only consider odds with at least one matching printing
+        let odds: Vec<(&RarityOdds, usize)> = self.layout.slots[slot]
+            .odds
+            .iter()
+            .map(|odds| {
+                let available = odds
+                    .rarities
+                    .iter()
+                    .filter_map(|rarity| self.pools.get(rarity))
+                    .map(|pool| pool.len())
+                    .sum();
+                (odds, available)
+            })
+            .filter(|(odds, available)| odds.weight > 0 && *available > 0)
+            .collect();
+
+        if odds.is_empty() {
+            let index = self.rng.gen_range(0..self.printings.len());
+            return self.printings[index].clone();
+        }
+
+        // Select the rarities of the card
+        let total_weight: u32 = odds.iter().map(|(odds, _)| odds.weight).sum();
+        let mut roll = self.rng.gen_range(0..total_weight);
+        let mut selected = odds[0];
+        for entry in odds.iter() {
+            if roll < entry.0.weight {
+                selected = *entry;
+                break;
+            }
+            roll -= entry.0.weight;
+        }
+
+        // Select a printing with one of the rarities
+        let mut index = self.rng.gen_range(0..selected.1);
+        for pool in selected
+            .0
+            .rarities
+            .iter()
+            .filter_map(|rarity| self.pools.get(rarity))
+        {
+            if index < pool.len() {
+                return pool[index].clone();
+            }
+            index -= pool.len();
+        }
+
+        unreachable!("The selected odds have {} printings.", selected.1);
+    }
+
+    /// Counts a generated copy of the card with `id` towards its limit. Removes all of its
+    /// printings from the card pool once the limit is reached.
+    fn take(&mut self, id: u32) {
+        if let Some(remaining) = self.limits.get_mut(&id) {
+            *remaining -= 1;
+
+            if *remaining == 0 {
+                self.printings.retain(|printing| printing.id != id);
+                for pool in self.pools.values_mut() {
+                    pool.retain(|printing| printing.id != id);
+                }
+            }
+        }
+    }
+}
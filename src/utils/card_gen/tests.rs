@@ -17,6 +17,9 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
 
+use crate::ext_data::banlists::LimitStatus;
+use crate::ext_data::cardsets::Printing;
+use crate::test_fixtures;
 use crate::utils::cache::CACHE;
 use crate::utils::http;
 
@@ -58,6 +61,142 @@ fn test_new_dedup() {
     assert_eq!(cg_dd.remaining_cards, 3);
 }
 
+#[test]
+fn test_seeded() {
+    let cards: Vec<u32> = (0..100).collect();
+    let mut cg_a = CardGenerator::from_seed(cards.to_vec(), 3, 42);
+    let mut cg_b = CardGenerator::from_seed(cards.to_vec(), 3, 42);
+    let mut cg_c = CardGenerator::from_seed(cards.to_vec(), 3, 43);
+
+    let generated = cg_a.batch_generate(10, 10);
+    assert_eq!(generated, cg_b.batch_generate(10, 10));
+    assert_ne!(generated, cg_c.batch_generate(10, 10));
+
+    // All 300 copies can be generated exactly once
+    let mut rest = generated.concat();
+    rest.extend(cg_a.generate(300));
+    rest.sort_unstable();
+    assert_eq!(rest.len(), 300);
+    assert!(rest.chunks(3).enumerate().all(|(i, c)| c == [i as u32; 3]));
+}
+
+#[test]
+fn test_banlist() {
+    let banlist = Banlist::from_iter([
+        (1, LimitStatus::Forbidden),
+        (2, LimitStatus::Limited),
+        (3, LimitStatus::SemiLimited),
+    ]);
+
+    let mut cg = CardGenerator::from_seed_banlist(vec![1, 2, 3, 4, 4], 0, &banlist, 42);
+    let mut generated = cg.generate(10);
+    generated.sort_unstable();
+    assert_eq!(generated, vec![2, 3, 3, 4, 4, 4]);
+
+    let mut cg = CardGenerator::from_banlist(vec![1, 2, 3, 4], 2, &banlist);
+    assert_eq!(cg.card_quantities, Some(vec![1, 2, 2]));
+    assert_eq!(cg.generate(10).len(), 5);
+}
+
+#[test]
+fn test_seed_from_str() {
+    assert_eq!(seed_from_str(""), 0xcbf29ce484222325);
+    assert_eq!(seed_from_str("a"), 0xaf63dc4c8601ec8c);
+    assert_ne!(seed_from_str("draft-1"), seed_from_str("draft-2"));
+}
+
+fn init_printings() -> Vec<Printing> {
+    let rarities = [
+        "Common",
+        "Common",
+        "Common",
+        "Rare",
+        "Super Rare",
+        "Ultra Rare",
+    ];
+
+    rarities
+        .iter()
+        .enumerate()
+        .map(|(i, rarity)| test_fixtures::printing(i as u32, "TST", rarity))
+        .collect()
+}
+
+#[test]
+fn test_pack_layout() {
+    let mut pg = PackGenerator::from_seed(init_printings(), PackLayout::booster(), 1);
+    assert_eq!(pg.layout().size(), 9);
+
+    for pack in pg.batch_generate(50) {
+        assert_eq!(pack.len(), 9);
+        assert!(pack[..7].iter().all(|p| p.rarity == "Common"));
+        assert_eq!(pack[7].rarity, "Rare");
+        // There are no secret rares in the card pool
+        assert!(pack[8].rarity == "Super Rare" || pack[8].rarity == "Ultra Rare");
+    }
+}
+
+#[test]
+fn test_pack_odds() {
+    let layout = PackLayout {
+        slots: vec![
+            RaritySlot {
+                count: 1,
+                odds: vec![
+                    RarityOdds {
+                        rarities: vec!["Ultra Rare".to_string()],
+                        weight: 1,
+                    },
+                    RarityOdds {
+                        rarities: vec!["Super Rare".to_string()],
+                        weight: 0,
+                    },
+                ],
+            },
+            RaritySlot::fixed(2, &["Starlight Rare"]),
+        ],
+    };
+    let mut pg = PackGenerator::from_seed(init_printings(), layout.clone(), 1);
+    let pack = pg.generate();
+
+    assert_eq!(pack[0].id, 5);
+    // Slots without matching printings are filled from the whole card pool
+    assert_eq!(pack.len(), 3);
+
+    let mut pg_a = PackGenerator::from_seed(init_printings(), layout.clone(), 2);
+    let mut pg_b = PackGenerator::from_seed(init_printings(), layout.clone(), 2);
+    assert_eq!(pg_a.batch_generate(5), pg_b.batch_generate(5));
+    assert!(PackGenerator::new(Vec::new(), layout).generate().is_empty());
+}
+
+#[test]
+fn test_pack_banlist() {
+    // Cards 0 to 2 are the only commons
+    let banlist = Banlist::from_iter([
+        (0, LimitStatus::Forbidden),
+        (1, LimitStatus::Limited),
+        (2, LimitStatus::SemiLimited),
+    ]);
+    let layout = PackLayout {
+        slots: vec![RaritySlot::fixed(1, &["Common"])],
+    };
+
+    let mut pg = PackGenerator::from_seed_banlist(init_printings(), layout, &banlist, 1);
+    let mut commons: Vec<u32> = pg.batch_generate(3).concat().iter().map(|p| p.id).collect();
+    commons.sort_unstable();
+    assert_eq!(commons, vec![1, 2, 2]);
+
+    // Once all commons are used up, the slot is filled from the whole card pool
+    let pack = pg.generate();
+    assert_eq!(pack.len(), 1);
+    assert_ne!(pack[0].rarity, "Common");
+
+    let mut pg = PackGenerator::from_banlist(init_printings(), PackLayout::booster(), &banlist);
+    for _ in 0..10 {
+        assert!(pg.generate().iter().all(|p| p.id != 0));
+    }
+}
+
 #[test]
 fn test_real() {
     http::load_local_data();
@@ -0,0 +1,101 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::cardinfo::{Ability, Attribute, Card, FrameKind, Property, Race};
+
+/// A condition cards are matched against. Filters can be combined with [`Filter::And`],
+/// [`Filter::Or`] and [`Filter::Not`].
+///
+/// Text filters ignore case. Numeric filters never match cards without the respective value, e.g.
+/// [`Filter::Atk`] never matches Spells or monsters with `?` ATK.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum Filter {
+    /// The name contains the text.
+    Name(String),
+    /// The card text contains the text.
+    Description(String),
+    /// The card belongs to the archetype.
+    Archetype(String),
+    Attribute(Attribute),
+    Race(Race),
+    Property(Property),
+    /// The card has the frame, see [`CardKind::has_frame`][`crate::ext_data::cardinfo::CardKind::has_frame`].
+    Frame(FrameKind),
+    Ability(Ability),
+    /// Level of a monster that is neither an Xyz nor a Link monster.
+    Level(RangeInclusive<u32>),
+    /// Rank of an Xyz monster.
+    Rank(RangeInclusive<u32>),
+    LinkRating(RangeInclusive<u32>),
+    Atk(RangeInclusive<u32>),
+    Def(RangeInclusive<u32>),
+    PendulumScale(RangeInclusive<u32>),
+    /// All filters match. An empty list matches every card.
+    And(Vec<Filter>),
+    /// Any filter matches. An empty list matches no card.
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+}
+
+impl Filter {
+    /// Whether or not `card` matches the filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `card` – Card to check.
+    pub fn matches(&self, card: &Card) -> bool {
+        match self {
+            Filter::Name(text) => contains_ignore_case(&card.name, text),
+            Filter::Description(text) => contains_ignore_case(&card.description, text),
+            Filter::Archetype(archetype) => card
+                .archetype
+                .as_ref()
+                .map_or(false, |a| a.eq_ignore_ascii_case(archetype)),
+            Filter::Attribute(attribute) => card.attribute == Some(*attribute),
+            Filter::Race(race) => card.race == Some(*race),
+            Filter::Property(property) => card.property == Some(*property),
+            Filter::Frame(frame) => card.kind.has_frame(*frame),
+            Filter::Ability(ability) => card.kind.has_ability(*ability),
+            Filter::Level(range) => match card.kind.frame {
+                FrameKind::Xyz | FrameKind::Link => false,
+                _ => in_range(card.level, range),
+            },
+            Filter::Rank(range) => card.kind.frame == FrameKind::Xyz && in_range(card.level, range),
+            Filter::LinkRating(range) => in_range(card.link_rating, range),
+            Filter::Atk(range) => in_range(card.atk, range),
+            Filter::Def(range) => in_range(card.def, range),
+            Filter::PendulumScale(range) => in_range(card.pend_scale, range),
+            Filter::And(filters) => filters.iter().all(|filter| filter.matches(card)),
+            Filter::Or(filters) => filters.iter().any(|filter| filter.matches(card)),
+            Filter::Not(filter) => !filter.matches(card),
+        }
+    }
+}
+
+/// Whether or not `text` contains `pattern`, ignoring case.
+fn contains_ignore_case(text: &str, pattern: &str) -> bool {
+    text.to_lowercase().contains(&pattern.to_lowercase())
+}
+
+/// Whether or not `value` is known and inside `range`.
+fn in_range<T: Into<u32>>(value: Option<T>, range: &RangeInclusive<u32>) -> bool {
+    value.map_or(false, |value| range.contains(&value.into()))
+}
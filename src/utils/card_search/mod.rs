@@ -0,0 +1,127 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! # Examples
+//!
+//! ```no_run
+//! use ygod_core::ext_data::cardinfo::{Attribute, FrameKind};
+//! use ygod_core::utils::card_search::{self, Filter, SortBy};
+//! use ygod_core::utils::{http, CACHE};
+//!
+//! http::load_local_data();
+//!
+//! // All DARK Synchro monsters with at least 2500 ATK that are not named "Dragon"
+//! let filter = Filter::And(vec![
+//!     Filter::Attribute(Attribute::Dark),
+//!     Filter::Frame(FrameKind::Synchro),
+//!     Filter::Atk(2500..=u32::MAX),
+//!     Filter::Not(Box::new(Filter::Name("Dragon".to_string()))),
+//! ]);
+//!
+//! let cache = CACHE.lock().unwrap();
+//! let cards = cache.search(&filter, SortBy::Atk);
+//!
+//! // The same search as a query
+//! let filter = card_search::parse("attr:dark t:synchro atk>=2500 -dragon").unwrap();
+//! let cards = cache.search(&filter, SortBy::Atk);
+//!
+//! // Look up a misspelled card name
+//! let card = cache
+//!     .name_index
+//!     .find_best("blue eyes white dragn", card_search::DEFAULT_THRESHOLD)
+//!     .map(|found| &cache.cardinfo[&found.id]);
+//! ```
+
+/// Conditions cards are matched against.
+mod filter;
+/// Fuzzy lookup of card names.
+mod name_index;
+/// Text query syntax for filters.
+mod query;
+
+pub use filter::Filter;
+pub use name_index::{NameIndex, NameMatch, DEFAULT_THRESHOLD};
+pub use query::{parse, QueryParseError};
+
+pub(crate) use name_index::normalize;
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ext_data::cardinfo::{Card, CardinfoMetaType};
+
+/// Order of search results. Cards that are equal in the sort key are sorted by name and then id.
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    #[default]
+    Name,
+    Id,
+    /// Ascending ATK. Cards without ATK come last.
+    Atk,
+    /// Ascending DEF. Cards without DEF come last.
+    Def,
+    /// Ascending Level, Rank or Link Rating. Cards without any come last.
+    Level,
+}
+
+/// Returns all cards of `cardinfo` that match `filter`, sorted by `sort_by`.
+///
+/// # Arguments
+///
+/// * `cardinfo` – Card data to search, usually [`Cache::cardinfo`][`crate::utils::Cache::cardinfo`].
+/// * `filter` – Condition the returned cards match.
+/// * `sort_by` – Order of the returned cards.
+pub fn search<'a>(
+    cardinfo: &'a CardinfoMetaType,
+    filter: &Filter,
+    sort_by: SortBy,
+) -> Vec<&'a Card> {
+    let mut cards: Vec<&Card> = cardinfo
+        .values()
+        .filter(|card| filter.matches(card))
+        .collect();
+
+    cards.sort_by(|a, b| {
+        let ordering = match sort_by {
+            SortBy::Name | SortBy::Id => Ordering::Equal,
+            SortBy::Atk => cmp_known(a.atk, b.atk),
+            SortBy::Def => cmp_known(a.def, b.def),
+            SortBy::Level => cmp_known(a.level.or(a.link_rating), b.level.or(b.link_rating)),
+        };
+
+        match sort_by {
+            SortBy::Id => ordering.then(a.id.cmp(&b.id)),
+            _ => ordering.then(a.name.cmp(&b.name)).then(a.id.cmp(&b.id)),
+        }
+    });
+
+    return cards;
+}
+
+/// Compares two optional values, sorting `None` after any known value.
+fn cmp_known<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,159 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ext_data::cardinfo::CardinfoMetaType;
+
+/// Minimum score of a match that is usually good enough to be meant by a misspelled name.
+pub const DEFAULT_THRESHOLD: f32 = 0.5;
+
+/// A card found by [`NameIndex::find`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NameMatch {
+    pub id: u32,
+    /// Similarity of the name to the query, from `0.0` to `1.0` for an exact match. Case,
+    /// punctuation and extra spaces are ignored.
+    pub score: f32,
+}
+
+/// Index for looking up cards by misspelled names. Names are compared by the trigrams (sequences
+/// of three characters) they share.
+///
+/// The index is built once from the card data, usually with [`Cache`][`crate::utils::Cache`],
+/// which keeps an index of all cards in
+/// [`name_index`][`crate::utils::Cache::name_index`].
+#[derive(Clone, Default, Debug)]
+pub struct NameIndex {
+    /// Id, normalized name and number of unique trigrams of each card.
+    entries: Vec<(u32, String, usize)>,
+    /// Indices of the entries that contain a trigram.
+    trigrams: HashMap<[char; 3], Vec<usize>>,
+}
+
+impl NameIndex {
+    /// Constructs a [`NameIndex`] of all cards in `cardinfo`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cardinfo` – Card data to index.
+    pub fn new(cardinfo: &CardinfoMetaType) -> Self {
+        let mut index = Self::default();
+
+        for card in cardinfo.values() {
+            let name = normalize(&card.name);
+            let trigrams = trigrams_of(&name);
+
+            for trigram in trigrams.iter() {
+                index
+                    .trigrams
+                    .entry(*trigram)
+                    .or_default()
+                    .push(index.entries.len());
+            }
+            index.entries.push((card.id, name, trigrams.len()));
+        }
+
+        return index;
+    }
+
+    /// Returns all cards with a name similar to `query`, best matches first.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` – Name to look up, e.g. `blue eyes white dragn`.
+    /// * `threshold` – Minimum score of the returned matches, see [`DEFAULT_THRESHOLD`].
+    pub fn find(&self, query: &str, threshold: f32) -> Vec<NameMatch> {
+        let query = normalize(query);
+        let query_trigrams = trigrams_of(&query);
+
+        // Count the trigrams each name shares with the query
+        let mut shared: HashMap<usize, usize> = HashMap::new();
+        for trigram in query_trigrams.iter() {
+            for entry in self.trigrams.get(trigram).into_iter().flatten() {
+                *shared.entry(*entry).or_insert(0) += 1;
+            }
+        }
+
+        let mut matches: Vec<(&String, NameMatch)> = shared
+            .into_iter()
+            .map(|(entry, count)| {
+                let (id, name, trigrams) = &self.entries[entry];
+                // Sørensen–Dice coefficient of the trigrams
+                let score = (2 * count) as f32 / (query_trigrams.len() + trigrams) as f32;
+
+                (
+                    name,
+                    NameMatch {
+                        id: *id,
+                        score: score,
+                    },
+                )
+            })
+            .filter(|(_, found)| found.score >= threshold)
+            .collect();
+
+        matches.sort_by(|(name_a, a), (name_b, b)| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap()
+                .then(name_a.cmp(name_b))
+        });
+
+        return matches.into_iter().map(|(_, found)| found).collect();
+    }
+
+    /// Returns the card with the name most similar to `query`, or `None` if no name reaches
+    /// `threshold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` – Name to look up.
+    /// * `threshold` – Minimum score of the match, see [`DEFAULT_THRESHOLD`].
+    pub fn find_best(&self, query: &str, threshold: f32) -> Option<NameMatch> {
+        self.find(query, threshold).into_iter().next()
+    }
+
+    /// Number of indexed cards.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether or not the index contains no cards.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Returns `name` in lowercase with punctuation replaced by single spaces.
+pub(crate) fn normalize(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Returns the unique trigrams of a normalized name, including the start and end of the name.
+fn trigrams_of(name: &str) -> HashSet<[char; 3]> {
+    let chars: Vec<char> = format!("  {} ", name).chars().collect();
+
+    chars
+        .windows(3)
+        .map(|window| [window[0], window[1], window[2]])
+        .collect()
+}
@@ -0,0 +1,319 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use pest::error::{Error, InputLocation};
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::ext_data::cardinfo::{Ability, Attribute, FrameKind, Property, Race};
+
+use super::Filter;
+
+/// Parser for card search queries, generated from `query.pest`.
+#[derive(Parser)]
+#[grammar = "utils/card_search/query.pest"]
+struct QueryParser;
+
+/// Position and reason of a malformed search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryParseError {
+    /// Column of the offending token, starting at 1.
+    pub column: usize,
+    /// The offending token. Empty if the query ended unexpectedly.
+    pub token: String,
+    pub message: String,
+}
+
+impl QueryParseError {
+    /// Constructs a [`QueryParseError`] pointing to `pair`.
+    fn at(pair: &Pair<Rule>, message: String) -> Self {
+        let (_, column) = pair.line_col();
+
+        QueryParseError {
+            column,
+            token: pair.as_str().to_string(),
+            message,
+        }
+    }
+
+    /// Converts a syntax error of `query`.
+    fn from_pest(error: Error<Rule>, query: &str) -> Self {
+        let start = match error.location {
+            InputLocation::Pos(pos) => pos,
+            InputLocation::Span((start, _)) => start,
+        };
+        let token = query[start..]
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+
+        QueryParseError {
+            column: query[..start].chars().count() + 1,
+            token: token.to_string(),
+            message: error.variant.message().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for QueryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid query at column {} (`{}`): {}",
+            self.column, self.token, self.message
+        )
+    }
+}
+
+impl std::error::Error for QueryParseError {}
+
+/// Returns the [`Filter`] described by a search query.
+///
+/// A query consists of terms separated by spaces. All terms have to match, unless they are joined
+/// with `or`. Terms can be grouped with parentheses and negated with a leading `-`. Values that
+/// contain spaces or parentheses are put in double quotes.
+///
+/// A term without a key searches card names. Other terms have the form `key:value`:
+///
+/// * `n`, `name` – Name contains the value.
+/// * `o`, `text` – Card text contains the value.
+/// * `a`, `archetype` – Archetype is the value.
+/// * `attr`, `attribute` – Attribute, e.g. `attr:dark`.
+/// * `t`, `type` – Monster type, frame, Spell/Trap property or monster ability, e.g. `t:dragon`,
+///   `t:synchro`, `t:quick-play` or `t:tuner`.
+/// * `atk`, `def`, `lv`/`level`, `rank`, `link`, `scale` – Numeric values, which can also be
+///   compared with `>`, `>=`, `<` and `<=`, e.g. `atk>=2000`.
+///
+/// `!=` negates a term. An empty query matches every card.
+///
+/// # Arguments
+///
+/// * `query` – Search query, e.g. `atk>=2000 attr:dark t:dragon a:"Blue-Eyes" o:"destroy"`.
+pub fn parse(query: &str) -> Result<Filter, QueryParseError> {
+    let query_pair = QueryParser::parse(Rule::query, query)
+        .map_err(|error| QueryParseError::from_pest(error, query))?
+        .next()
+        .unwrap();
+
+    match query_pair
+        .into_inner()
+        .find(|pair| pair.as_rule() == Rule::or_expr)
+    {
+        Some(or_expr) => build(or_expr),
+        None => Ok(Filter::And(Vec::new())),
+    }
+}
+
+/// Builds the filter of an expression.
+fn build(pair: Pair<Rule>) -> Result<Filter, QueryParseError> {
+    match pair.as_rule() {
+        Rule::or_expr | Rule::and_expr => {
+            let is_or = pair.as_rule() == Rule::or_expr;
+            let mut filters = pair
+                .into_inner()
+                .map(build)
+                .collect::<Result<Vec<Filter>, QueryParseError>>()?;
+
+            if filters.len() == 1 {
+                return Ok(filters.remove(0));
+            }
+
+            if is_or {
+                Ok(Filter::Or(filters))
+            } else {
+                Ok(Filter::And(filters))
+            }
+        }
+        Rule::negation => Ok(Filter::Not(Box::new(build(
+            pair.into_inner().next().unwrap(),
+        )?))),
+        Rule::group => build(pair.into_inner().next().unwrap()),
+        Rule::word => Ok(Filter::Name(
+            pair.into_inner().next().unwrap().as_str().to_string(),
+        )),
+        Rule::field => build_field(pair),
+        _ => unreachable!("unexpected rule {:?}", pair.as_rule()),
+    }
+}
+
+/// Builds the filter of a `key:value` term.
+fn build_field(pair: Pair<Rule>) -> Result<Filter, QueryParseError> {
+    let mut inner = pair.into_inner();
+    let key = inner.next().unwrap();
+    let operator = inner.next().unwrap();
+    let value = inner.next().unwrap();
+    let text = value.as_str().to_string();
+
+    let numeric: Option<fn(RangeInclusive<u32>) -> Filter> =
+        match key.as_str().to_ascii_lowercase().as_str() {
+            "atk" => Some(Filter::Atk),
+            "def" => Some(Filter::Def),
+            "lv" | "level" => Some(Filter::Level),
+            "rank" => Some(Filter::Rank),
+            "link" => Some(Filter::LinkRating),
+            "scale" => Some(Filter::PendulumScale),
+            _ => None,
+        };
+
+    if let Some(filter) = numeric {
+        let number = text
+            .parse::<u32>()
+            .map_err(|_| QueryParseError::at(&value, format!("`{}` is not a number", text)))?;
+
+        return Ok(match operator.as_str() {
+            ":" | "=" => filter(number..=number),
+            "!=" => Filter::Not(Box::new(filter(number..=number))),
+            ">" => filter(number.saturating_add(1)..=u32::MAX),
+            ">=" => filter(number..=u32::MAX),
+            "<" => match number.checked_sub(1) {
+                Some(max) => filter(0..=max),
+                // Nothing is less than 0
+                None => Filter::Or(Vec::new()),
+            },
+            "<=" => filter(0..=number),
+            _ => unreachable!(),
+        });
+    }
+
+    let filter = match key.as_str().to_ascii_lowercase().as_str() {
+        "n" | "name" => Filter::Name(text),
+        "o" | "text" => Filter::Description(text),
+        "a" | "archetype" => Filter::Archetype(text),
+        "attr" | "attribute" => {
+            Filter::Attribute(Attribute::parse(&text).ok_or_else(|| {
+                QueryParseError::at(&value, format!("unknown attribute `{}`", text))
+            })?)
+        }
+        "t" | "type" => parse_type(&text)
+            .ok_or_else(|| QueryParseError::at(&value, format!("unknown type `{}`", text)))?,
+        unknown => {
+            return Err(QueryParseError::at(
+                &key,
+                format!("unknown key `{}`", unknown),
+            ))
+        }
+    };
+
+    match operator.as_str() {
+        ":" | "=" => Ok(filter),
+        "!=" => Ok(Filter::Not(Box::new(filter))),
+        _ => Err(QueryParseError::at(
+            &operator,
+            format!(
+                "`{}` can only be compared with `:`, `=` or `!=`",
+                key.as_str()
+            ),
+        )),
+    }
+}
+
+/// Returns the filter for a `type:` value. Values that name both a frame and a Spell/Trap property,
+/// like `ritual`, match either.
+fn parse_type(value: &str) -> Option<Filter> {
+    let normalized: String = value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .collect::<String>()
+        .to_lowercase();
+
+    let race = match normalized.as_str() {
+        "aqua" => Some(Race::Aqua),
+        "beast" => Some(Race::Beast),
+        "beastwarrior" => Some(Race::BeastWarrior),
+        "creatorgod" => Some(Race::CreatorGod),
+        "cyberse" => Some(Race::Cyberse),
+        "dinosaur" => Some(Race::Dinosaur),
+        "divinebeast" => Some(Race::DivineBeast),
+        "dragon" => Some(Race::Dragon),
+        "fairy" => Some(Race::Fairy),
+        "fiend" => Some(Race::Fiend),
+        "fish" => Some(Race::Fish),
+        "illusion" => Some(Race::Illusion),
+        "insect" => Some(Race::Insect),
+        "machine" => Some(Race::Machine),
+        "plant" => Some(Race::Plant),
+        "psychic" => Some(Race::Psychic),
+        "pyro" => Some(Race::Pyro),
+        "reptile" => Some(Race::Reptile),
+        "rock" => Some(Race::Rock),
+        "seaserpent" => Some(Race::SeaSerpent),
+        "spellcaster" => Some(Race::Spellcaster),
+        "thunder" => Some(Race::Thunder),
+        "warrior" => Some(Race::Warrior),
+        "wingedbeast" => Some(Race::WingedBeast),
+        "wyrm" => Some(Race::Wyrm),
+        "zombie" => Some(Race::Zombie),
+        _ => None,
+    };
+
+    let frame = match normalized.as_str() {
+        "normal" => Some(FrameKind::Normal),
+        "effect" => Some(FrameKind::Effect),
+        "ritual" => Some(FrameKind::Ritual),
+        "fusion" => Some(FrameKind::Fusion),
+        "synchro" => Some(FrameKind::Synchro),
+        "xyz" => Some(FrameKind::Xyz),
+        "link" => Some(FrameKind::Link),
+        "pendulum" => Some(FrameKind::Pendulum),
+        "spell" => Some(FrameKind::Spell),
+        "trap" => Some(FrameKind::Trap),
+        "token" => Some(FrameKind::Token),
+        "skill" => Some(FrameKind::Skill),
+        _ => None,
+    };
+
+    let property = match normalized.as_str() {
+        "normal" => Some(Property::Normal),
+        "continuous" => Some(Property::Continuous),
+        "counter" => Some(Property::Counter),
+        "equip" => Some(Property::Equip),
+        "field" => Some(Property::Field),
+        "quickplay" => Some(Property::QuickPlay),
+        "ritual" => Some(Property::Ritual),
+        _ => None,
+    };
+
+    let ability = match normalized.as_str() {
+        "tuner" => Some(Ability::Tuner),
+        "flip" => Some(Ability::Flip),
+        "gemini" => Some(Ability::Gemini),
+        "spirit" => Some(Ability::Spirit),
+        "toon" => Some(Ability::Toon),
+        "union" => Some(Ability::Union),
+        _ => None,
+    };
+
+    let mut filters: Vec<Filter> = [
+        race.map(Filter::Race),
+        frame.map(Filter::Frame),
+        property.map(Filter::Property),
+        ability.map(Filter::Ability),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    match filters.len() {
+        0 => None,
+        1 => Some(filters.remove(0)),
+        _ => Some(Filter::Or(filters)),
+    }
+}
@@ -0,0 +1,286 @@
+/*
+YGO Destiny – A Yu-Gi-Oh! sealed draft simulator written in rust.
+Copyright (C) 2022  myujiku
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License version 3 as
+published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+use crate::ext_data::cardinfo::{Attribute, FrameKind, Property, Race};
+use crate::test_fixtures;
+
+const CARDINFO: &str = r#"{"data": [
+    {"id": 46986414, "name": "Dark Magician", "type": "Normal Monster", "desc": "The ultimate wizard.",
+     "atk": 2500, "def": 2100, "level": 7, "race": "Spellcaster", "attribute": "DARK",
+     "archetype": "Dark Magician"},
+    {"id": 89631139, "name": "Blue-Eyes White Dragon", "type": "Normal Monster",
+     "desc": "This legendary dragon is a powerful engine of destruction.",
+     "atk": 3000, "def": 2500, "level": 8, "race": "Dragon", "attribute": "LIGHT",
+     "archetype": "Blue-Eyes"},
+    {"id": 84013237, "name": "Number 39: Utopia", "type": "XYZ Monster",
+     "desc": "2 Level 4 monsters", "atk": 2500, "def": 2000, "level": 4, "race": "Warrior",
+     "attribute": "LIGHT", "archetype": "Utopia"},
+    {"id": 1861629, "name": "Decode Talker", "type": "Link Monster",
+     "desc": "2+ Effect Monsters", "atk": 2300, "linkval": 3, "race": "Cyberse",
+     "attribute": "DARK", "archetype": "Code Talker"},
+    {"id": 16178681, "name": "Odd-Eyes Pendulum Dragon", "type": "Pendulum Effect Monster",
+     "desc": "Destroy this card.", "atk": 2500, "def": 2000, "level": 7, "scale": 4,
+     "race": "Dragon", "attribute": "DARK", "archetype": "Odd-Eyes"},
+    {"id": 5318639, "name": "Mystical Space Typhoon", "type": "Spell Card",
+     "desc": "Target 1 Spell/Trap on the field; destroy that target.", "race": "Quick-Play"}
+]}"#;
+
+fn init_cardinfo() -> CardinfoMetaType {
+    test_fixtures::parse_cardinfo(CARDINFO)
+}
+
+/// Returns the names of the found cards.
+fn names(cardinfo: &CardinfoMetaType, filter: Filter, sort_by: SortBy) -> Vec<&str> {
+    search(cardinfo, &filter, sort_by)
+        .iter()
+        .map(|card| card.name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_filters() {
+    let cardinfo = init_cardinfo();
+
+    assert_eq!(
+        names(&cardinfo, Filter::Name("eyes".to_string()), SortBy::Name),
+        vec!["Blue-Eyes White Dragon", "Odd-Eyes Pendulum Dragon"]
+    );
+    assert_eq!(
+        names(
+            &cardinfo,
+            Filter::Description("DESTROY".to_string()),
+            SortBy::Name
+        ),
+        vec!["Mystical Space Typhoon", "Odd-Eyes Pendulum Dragon"]
+    );
+    assert_eq!(
+        names(
+            &cardinfo,
+            Filter::Archetype("blue-eyes".to_string()),
+            SortBy::Name
+        ),
+        vec!["Blue-Eyes White Dragon"]
+    );
+    assert_eq!(
+        names(&cardinfo, Filter::Race(Race::Dragon), SortBy::Name).len(),
+        2
+    );
+    assert_eq!(
+        names(
+            &cardinfo,
+            Filter::Property(Property::QuickPlay),
+            SortBy::Name
+        ),
+        vec!["Mystical Space Typhoon"]
+    );
+    assert_eq!(
+        names(&cardinfo, Filter::Frame(FrameKind::Pendulum), SortBy::Name),
+        vec!["Odd-Eyes Pendulum Dragon"]
+    );
+    assert_eq!(
+        names(&cardinfo, Filter::Frame(FrameKind::Effect), SortBy::Name),
+        vec!["Odd-Eyes Pendulum Dragon"]
+    );
+
+    // Ranks and Link Ratings are not Levels
+    assert_eq!(
+        names(&cardinfo, Filter::Level(1..=4), SortBy::Name),
+        Vec::<&str>::new()
+    );
+    assert_eq!(
+        names(&cardinfo, Filter::Rank(4..=4), SortBy::Name),
+        vec!["Number 39: Utopia"]
+    );
+    assert_eq!(
+        names(&cardinfo, Filter::LinkRating(2..=3), SortBy::Name),
+        vec!["Decode Talker"]
+    );
+    assert_eq!(
+        names(&cardinfo, Filter::PendulumScale(0..=4), SortBy::Name),
+        vec!["Odd-Eyes Pendulum Dragon"]
+    );
+
+    // Cards without DEF never match
+    assert_eq!(
+        names(&cardinfo, Filter::Def(0..=u32::MAX), SortBy::Name).len(),
+        4
+    );
+}
+
+#[test]
+fn test_combined() {
+    let cardinfo = init_cardinfo();
+
+    let filter = Filter::And(vec![
+        Filter::Attribute(Attribute::Dark),
+        Filter::Atk(2500..=u32::MAX),
+        Filter::Not(Box::new(Filter::Race(Race::Dragon))),
+    ]);
+    assert_eq!(
+        names(&cardinfo, filter, SortBy::Name),
+        vec!["Dark Magician"]
+    );
+
+    let filter = Filter::Or(vec![
+        Filter::Frame(FrameKind::Spell),
+        Filter::Frame(FrameKind::Link),
+    ]);
+    assert_eq!(
+        names(&cardinfo, filter, SortBy::Name),
+        vec!["Decode Talker", "Mystical Space Typhoon"]
+    );
+
+    assert_eq!(
+        names(&cardinfo, Filter::And(Vec::new()), SortBy::Name).len(),
+        6
+    );
+    assert!(names(&cardinfo, Filter::Or(Vec::new()), SortBy::Name).is_empty());
+}
+
+#[test]
+fn test_sort() {
+    let cardinfo = init_cardinfo();
+    let monsters = Filter::Not(Box::new(Filter::Frame(FrameKind::Spell)));
+
+    assert_eq!(
+        names(&cardinfo, monsters.clone(), SortBy::Atk),
+        vec![
+            "Decode Talker",
+            "Dark Magician",
+            "Number 39: Utopia",
+            "Odd-Eyes Pendulum Dragon",
+            "Blue-Eyes White Dragon"
+        ]
+    );
+    assert_eq!(
+        names(&cardinfo, monsters, SortBy::Level)[..2],
+        ["Decode Talker", "Number 39: Utopia"]
+    );
+
+    let ids: Vec<u32> = search(&cardinfo, &Filter::And(Vec::new()), SortBy::Id)
+        .iter()
+        .map(|card| card.id)
+        .collect();
+    assert_eq!(ids[0], 1861629);
+    assert_eq!(ids[5], 89631139);
+
+    // Cards without ATK come last
+    assert_eq!(
+        *names(&cardinfo, Filter::And(Vec::new()), SortBy::Atk)
+            .last()
+            .unwrap(),
+        "Mystical Space Typhoon"
+    );
+}
+
+#[test]
+fn test_query() {
+    let cardinfo = init_cardinfo();
+    let query = |query: &str| names(&cardinfo, parse(query).unwrap(), SortBy::Name);
+
+    assert_eq!(
+        parse(r#"atk>=2000 attr:dark type:dragon a:"Odd-Eyes" o:"destroy""#).unwrap(),
+        Filter::And(vec![
+            Filter::Atk(2000..=u32::MAX),
+            Filter::Attribute(Attribute::Dark),
+            Filter::Race(Race::Dragon),
+            Filter::Archetype("Odd-Eyes".to_string()),
+            Filter::Description("destroy".to_string()),
+        ])
+    );
+    assert_eq!(parse("  ").unwrap(), Filter::And(Vec::new()));
+
+    assert_eq!(query("eyes -pendulum"), vec!["Blue-Eyes White Dragon"]);
+    assert_eq!(
+        query("t:spell or (t:xyz AND rank:4)"),
+        vec!["Mystical Space Typhoon", "Number 39: Utopia"]
+    );
+    assert_eq!(query("t:quick-play"), vec!["Mystical Space Typhoon"]);
+    assert_eq!(query("t:normal atk<3000"), vec!["Dark Magician"]);
+    assert_eq!(query("link>2 def<0"), Vec::<&str>::new());
+    assert_eq!(
+        query("attr!=light lv:7 scale>=1"),
+        vec!["Odd-Eyes Pendulum Dragon"]
+    );
+    assert_eq!(query(r#"n:"Number 39""#), vec!["Number 39: Utopia"]);
+
+    // Words that only start like a keyword are names
+    assert_eq!(query("orb"), Vec::<&str>::new());
+}
+
+#[test]
+fn test_query_error() {
+    let error = parse("atk>=2000 attr:dork").unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (16, "dork"));
+
+    let error = parse("t:dragon atk>=high").unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (15, "high"));
+
+    let error = parse("cost:3").unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (1, "cost"));
+
+    let error = parse("a>Blue-Eyes").unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (2, ">"));
+
+    // The query ends unexpectedly
+    let error = parse("(t:dragon or").unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (13, ""));
+
+    // Unclosed quotes
+    let error = parse(r#"dark o:"destroy"#).unwrap_err();
+    assert_eq!((error.column, error.token.as_str()), (8, "\"destroy"));
+}
+
+#[test]
+fn test_name_index() {
+    let cardinfo = init_cardinfo();
+    let index = NameIndex::new(&cardinfo);
+    assert_eq!(index.len(), 6);
+
+    let found = index.find("blue eyes white dragn", DEFAULT_THRESHOLD);
+    assert_eq!(found[0].id, 89631139);
+    assert!(found[0].score < 1.0);
+    assert!(found.iter().all(|found| found.score >= DEFAULT_THRESHOLD));
+
+    let exact = index
+        .find_best("DARK MAGICIAN!", DEFAULT_THRESHOLD)
+        .unwrap();
+    assert_eq!(
+        exact,
+        NameMatch {
+            id: 46986414,
+            score: 1.0
+        }
+    );
+
+    // Results are ranked by similarity
+    let found = index.find("eyes dragon", 0.0);
+    assert!(found.len() >= 2);
+    assert!(found.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+    assert_eq!(index.find_best("number 93 utopia", 0.9), None);
+    assert_eq!(
+        index
+            .find_best("number 39 utopa", DEFAULT_THRESHOLD)
+            .unwrap()
+            .id,
+        84013237
+    );
+    assert!(index.find("", DEFAULT_THRESHOLD).is_empty());
+}
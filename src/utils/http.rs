@@ -15,7 +15,7 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 
 use bincode::{
@@ -23,13 +23,14 @@ use bincode::{
     serde::decode_from_slice as decode,
     serde::encode_to_vec as encode,
 };
-use regex::Regex;
 
+use crate::ext_data::banlists::{BanlistParseError, BanlistSource};
+use crate::ext_data::cardsets::Printing;
 use crate::ext_data::{banlists, cardinfo, cardsets, vercheck};
 use crate::utils::{CACHE, PATHS};
 
 pub type ResponseType = Result<String, reqwest::Error>;
-pub type CardSetMapType = HashMap<String, Vec<u32>>;
+pub type CardSetMapType = HashMap<String, Vec<Printing>>;
 
 /// Configuration for all files managed by [`bincode`].
 static BINCODE_CONFIG: Configuration<BigEndian, Fixint> = bincode::config::standard()
@@ -41,13 +42,16 @@ static BINCODE_CONFIG: Configuration<BigEndian, Fixint> = bincode::config::stand
 #[derive(Debug)]
 pub enum UpdateStatus {
     Complete,
+    /// The card data or the TCG banlists could not be downloaded. Nothing was updated.
     Failed,
+    /// The banlists of a source could not be parsed. Nothing was updated.
+    InvalidBanlist(BanlistSource, BanlistParseError),
     Incomplete,
 }
 
 /// Container for results of requests to external sources.
 struct Responses {
-    banlists: ResponseType,
+    banlists: Vec<(BanlistSource, ResponseType)>,
     cardinfo: ResponseType,
     cardsets: ResponseType,
 }
@@ -64,20 +68,48 @@ pub fn update() -> UpdateStatus {
     let data: Responses = get_data();
 
     // Exit if the Result of get_data is an error
-    if data.banlists.is_err() || data.cardinfo.is_err() || data.cardsets.is_err() {
+    if data.cardinfo.is_err() || data.cardsets.is_err() {
         return UpdateStatus::Failed;
     }
 
-    // Remove lines starting with '#' from banlists response text
-    let banlists_raw: String = Regex::new(r"#.*\n")
-        .unwrap()
-        .replace_all(data.banlists.unwrap().as_str(), "")
-        .to_string();
     let cardinfo_raw: String = data.cardinfo.unwrap();
     let cardsets_raw: String = data.cardsets.unwrap();
 
+    // Banlists of optional sources that can not be updated are kept from the previous update
+    let mut previous_banlists = CACHE.lock().unwrap().banlists.clone();
+
     // Parse responses
-    let parsed_banlists = banlists::parse(banlists_raw.as_str());
+    let mut parsed_banlists: banlists::BanlistSourcesMetaType = BTreeMap::new();
+    for (source, response) in data.banlists {
+        let banlists_raw = match response {
+            Ok(banlists_raw) => banlists_raw,
+            // Only the TCG banlists are required
+            Err(_) if source != BanlistSource::Tcg => {
+                if let Some(previous) = previous_banlists.remove(&source) {
+                    parsed_banlists.insert(source, previous);
+                }
+                continue;
+            }
+            Err(_) => return UpdateStatus::Failed,
+        };
+
+        let parsed = match &source {
+            BanlistSource::Format(_) => banlists::parse_format(banlists_raw.as_str()),
+            _ => banlists::parse(banlists_raw.as_str()),
+        };
+
+        match (parsed, &source) {
+            (Ok(parsed), _) => {
+                parsed_banlists.insert(source, parsed);
+            }
+            (Err(_), BanlistSource::Format(_)) => {
+                if let Some(previous) = previous_banlists.remove(&source) {
+                    parsed_banlists.insert(source, previous);
+                }
+            }
+            (Err(error), _) => return UpdateStatus::InvalidBanlist(source, error),
+        }
+    }
 
     let mut card_set_map: CardSetMapType = HashMap::new();
     let parsed_cardinfo = cardinfo::parse(cardinfo_raw.as_str(), &mut card_set_map);
@@ -110,9 +142,10 @@ pub fn update() -> UpdateStatus {
     return UpdateStatus::Complete;
 }
 
-/// Convenience function for making requests.
+/// Convenience function for making requests. Responses with an error status, like 404, are
+/// returned as errors.
 pub fn get_response(url: &str) -> ResponseType {
-    Ok(reqwest::blocking::get(url)?.text()?)
+    Ok(reqwest::blocking::get(url)?.error_for_status()?.text()?)
 }
 
 /// Loads data from local files into the cache.
@@ -127,10 +160,7 @@ pub fn load_local_data() {
     // Check if any errors occurred
     if files.iter().any(|f| f.is_err()) {
         // Files don't seem to be complete, so do an update
-        update();
-
-        // Save the update version so that the data is not re-downloaded immediately
-        fs::write(&PATHS.ext_data.version, update_version().unwrap()).unwrap();
+        update_and_save_version();
 
         return;
     }
@@ -139,35 +169,57 @@ pub fn load_local_data() {
     #[rustfmt::skip]
     let banlists = decode(
         files[0].as_ref().unwrap().as_ref(), BINCODE_CONFIG
-    ).unwrap().0;
+    );
 
     #[rustfmt::skip]
     let cardinfo = decode(
         files[1].as_ref().unwrap().as_ref(), BINCODE_CONFIG
-    ).unwrap().0;
+    );
 
     #[rustfmt::skip]
     let cardsets = decode(
         files[2].as_ref().unwrap().as_ref(), BINCODE_CONFIG
-    ).unwrap().0;
+    );
+
+    match (banlists, cardinfo, cardsets) {
+        (Ok(banlists), Ok(cardinfo), Ok(cardsets)) => {
+            update_cache(banlists.0, cardinfo.0, cardsets.0)
+        }
+        // Files were written with an outdated data layout, so do an update
+        _ => update_and_save_version(),
+    }
+}
+
+/// Calls [`update`] and saves the update version so that the data is not re-downloaded immediately.
+fn update_and_save_version() {
+    update();
 
-    update_cache(banlists, cardinfo, cardsets);
+    fs::write(&PATHS.ext_data.version, update_version().unwrap()).unwrap();
 }
 
 /// Updates the cache with new data.
 fn update_cache(
-    banlists: banlists::BanlistsMetaType,
+    banlists: banlists::BanlistSourcesMetaType,
     cardinfo: cardinfo::CardinfoMetaType,
     cardsets: cardsets::CardsetsMetaType,
 ) {
-    CACHE.lock().unwrap().banlists = banlists;
-    CACHE.lock().unwrap().cardinfo = cardinfo;
-    CACHE.lock().unwrap().cardsets = cardsets;
+    let mut cache = CACHE.lock().unwrap();
+
+    cache.banlists = banlists;
+    cache.cardinfo = cardinfo;
+    cache.cardsets = cardsets;
+    cache.rebuild_indexes();
 }
 
 fn get_data() -> Responses {
     Responses {
-        banlists: get_response(&banlists::EXT_URL),
+        banlists: BanlistSource::all()
+            .into_iter()
+            .map(|source| {
+                let response = get_response(&source.url());
+                (source, response)
+            })
+            .collect(),
         cardinfo: get_response(&cardinfo::EXT_URL),
         cardsets: get_response(&cardsets::EXT_URL),
     }
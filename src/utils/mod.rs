@@ -19,6 +19,8 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 mod cache;
 /// Random card selection for draft/sealed.
 pub mod card_gen;
+/// Searching and filtering card data.
+pub mod card_search;
 /// Interface for handling http requests.
 pub mod http;
 #[doc(hidden)]
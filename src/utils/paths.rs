@@ -76,20 +76,26 @@ impl ImagePaths {
 
 /// Container for paths to store user-created data in.
 pub struct UserPaths {
+    pub banlists: PathBuf,
     pub collections: PathBuf,
+    pub drafts: PathBuf,
 }
 
 impl UserPaths {
     /// * `root` – Path containing the user data directories.
     pub fn new(root: PathBuf) -> Self {
         Self {
+            banlists: root.join("banlists"),
             collections: root.join("collections"),
+            drafts: root.join("drafts"),
         }
     }
 
     /// Create all missing user path directories.
     pub fn ensure(&self) {
+        fs::create_dir_all(&self.banlists).unwrap();
         fs::create_dir_all(&self.collections).unwrap();
+        fs::create_dir_all(&self.drafts).unwrap();
     }
 }
 